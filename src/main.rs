@@ -2,6 +2,7 @@
 
 pub mod quad_storage;
 
+use ::rand::distributions::WeightedIndex;
 use ::rand::prelude::*;
 use serde::*;
 use std::collections::BTreeMap;
@@ -34,15 +35,19 @@ struct LearnType {
     wait_days: u8,
     count: u8,
     show_word: bool,
+    /// Повторения уровня делаются по одному в день, и между ними тоже ждётся `wait_days` дней. Иначе все `count` повторений делаются за один день
+    #[serde(default)]
+    per_day: bool,
 }
 
 impl std::fmt::Debug for LearnType {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "(wait {}, count {}, {})",
+            "(wait {}, count {}{}, {})",
             self.wait_days,
             self.count,
+            if self.per_day { " per day" } else { "" },
             if self.show_word { "show" } else { "not show" }
         )
     }
@@ -54,6 +59,7 @@ impl LearnType {
             wait_days,
             count,
             show_word: true,
+            per_day: false,
         }
     }
 
@@ -62,30 +68,94 @@ impl LearnType {
             wait_days,
             count,
             show_word: false,
+            per_day: false,
         }
     }
 }
 
+/// Начальный коэффициент лёгкости слова, как в SM-2
+const DEFAULT_EASE: f32 = 2.5;
+
+/// Минимальный коэффициент лёгкости слова
+const MIN_EASE: f32 = 1.3;
+
+/// На сколько уменьшается коэффициент лёгкости при неправильном ответе
+const EASE_PENALTY: f32 = 0.2;
+
+/// На сколько увеличивается коэффициент лёгкости, когда уровень пройден, как при лучшей оценке в SM-2
+const EASE_BONUS: f32 = 0.1;
+
+/// Сколько последних попыток хранится в истории слова
+const HISTORY_LEN: usize = 10;
+
+fn default_ease() -> f32 {
+    DEFAULT_EASE
+}
+
 impl LearnType {
-    fn can_learn_today(&self, last_learn: Day, today: Day) -> bool {
+    /// Сколько дней ждать с учётом коэффициента лёгкости слова. При коэффициенте по умолчанию совпадает с `wait_days`, а с каждым пройденным уровнем коэффициент растёт, и интервалы становятся длиннее.
+    fn scaled_wait_days(&self, ease: f32) -> u64 {
+        (self.wait_days as f32 * ease / DEFAULT_EASE).round() as u64
+    }
+
+    /// Сколько дней ждать перед следующим повторением, если на этом уровне уже сделано `current_count` повторений. В режиме `per_day` следующее повторение возможно не раньше следующего дня.
+    fn wait_days_for(&self, ease: f32, current_count: u8) -> u64 {
+        let days = self.scaled_wait_days(ease);
+        if self.per_day && current_count > 0 {
+            days.max(1)
+        } else {
+            days
+        }
+    }
+
+    /// Сколько повторений нужно на уровне. Уровень без повторений из старых сохранений всё равно проходится за одно повторение, иначе слово никогда бы с него не ушло.
+    fn repeats(&self) -> u8 {
+        self.count.max(1)
+    }
+
+    /// Уровень расписания, на котором находится слово. Если расписание темы короче уровня слова, то слово повторяется по последнему уровню.
+    fn at_level(type_count: &[LearnType], level: u8) -> Option<&LearnType> {
+        type_count.get(level as usize).or_else(|| type_count.last())
+    }
+
+    fn can_learn_today(&self, last_learn: Day, today: Day, ease: f32, current_count: u8) -> bool {
         if today.0 >= last_learn.0 {
-            today.0 - last_learn.0 >= self.wait_days as u64
+            today.0 - last_learn.0 >= self.wait_days_for(ease, current_count)
         } else {
             false
         }
     }
 }
 
+/// С какого интервала повторения слово считается зрелым, как в Anki
+const MATURE_INTERVAL_DAYS: u64 = 21;
+
+/// Насколько прочно изучаемое слово уже запомнено, по текущему интервалу повторения
+#[derive(Serialize, Deserialize, Clone, Copy, Ord, PartialOrd, Eq, PartialEq, Debug)]
+enum Maturity {
+    Young,
+    Mature,
+}
+
+impl Maturity {
+    fn name(&self) -> &'static str {
+        match self {
+            Maturity::Young => "Young",
+            Maturity::Mature => "Mature",
+        }
+    }
+}
+
 /// Статистика написаний для слова, дня или вообще
 #[derive(Default, Serialize, Deserialize, Clone, Copy, Debug)]
-struct TypingStats {
+pub struct TypingStats {
     right: u64,
     wrong: u64,
 }
 
 /// Обозначает одну пару слов рус-англ или англ-рус в статистике
 #[derive(Serialize, Deserialize, Clone, Debug)]
-enum WordStatus {
+pub enum WordStatus {
     /// Мы знали это слово раньше, его изучать не надо
     KnowPreviously,
 
@@ -105,6 +175,42 @@ enum WordStatus {
         /// Количество вводов для текущего уровня
         current_count: u8,
 
+        /// Коэффициент лёгкости слова, на него масштабируется время ожидания
+        #[serde(default = "default_ease")]
+        ease: f32,
+
+        /// Сколько раз подряд слово было введено неправильно
+        #[serde(default)]
+        consecutive_wrong: u8,
+
+        /// Всегда показывать слово (Some(true)) или всегда угадывать (Some(false)), независимо от `show_word` уровня
+        #[serde(default)]
+        force_mode: Option<bool>,
+
+        /// Темы, к которым относится слово
+        #[serde(default)]
+        tags: BTreeSet<String>,
+
+        /// Пример использования слова
+        #[serde(default)]
+        example: Option<String>,
+
+        /// Путь или ссылка на картинку для запоминания. В браузере картинка не показывается, а только открывается по ссылке.
+        #[serde(default)]
+        image_url: Option<String>,
+
+        /// Группа форм одного слова (спряжение, склонение), например `go`
+        #[serde(default)]
+        group: Option<String>,
+
+        /// Заметка пользователя: мнемоника, особенности употребления
+        #[serde(default)]
+        note: Option<String>,
+
+        /// Последние попытки: день и правильно ли было введено, не больше `HISTORY_LEN`
+        #[serde(default)]
+        history: Vec<(Day, bool)>,
+
         /// Статистика
         stats: TypingStats,
     },
@@ -113,80 +219,457 @@ enum WordStatus {
     Learned {
         translation: String,
 
+        /// Темы, к которым относится слово
+        #[serde(default)]
+        tags: BTreeSet<String>,
+
+        /// Пример использования слова
+        #[serde(default)]
+        example: Option<String>,
+
+        /// Путь или ссылка на картинку для запоминания. В браузере картинка не показывается, а только открывается по ссылке.
+        #[serde(default)]
+        image_url: Option<String>,
+
+        /// Группа форм одного слова (спряжение, склонение), например `go`
+        #[serde(default)]
+        group: Option<String>,
+
+        /// Заметка пользователя: мнемоника, особенности употребления
+        #[serde(default)]
+        note: Option<String>,
+
+        /// Последние попытки, перешли из изучения
+        #[serde(default)]
+        history: Vec<(Day, bool)>,
+
         /// Статистика
         stats: TypingStats,
     },
+
+    /// Изучение слова приостановлено, прогресс сохранён и восстанавливается при возвращении к изучению
+    Suspended {
+        translation: String,
+
+        last_learn: Day,
+        saved_level: u8,
+        saved_count: u8,
+
+        #[serde(default = "default_ease")]
+        saved_ease: f32,
+
+        #[serde(default)]
+        force_mode: Option<bool>,
+
+        #[serde(default)]
+        tags: BTreeSet<String>,
+
+        #[serde(default)]
+        example: Option<String>,
+
+        #[serde(default)]
+        image_url: Option<String>,
+
+        #[serde(default)]
+        group: Option<String>,
+
+        #[serde(default)]
+        note: Option<String>,
+
+        #[serde(default)]
+        history: Vec<(Day, bool)>,
+
+        stats: TypingStats,
+    },
+}
+
+/// Необязательные данные слова, которые переходят вместе с ним при смене статуса.
+///
+/// В сохранении они лежат прямо в полях `WordStatus`, потому что RON не поддерживает `#[serde(flatten)]` у структур.
+#[derive(Default, Clone, Debug)]
+struct WordExtras {
+    tags: BTreeSet<String>,
+    example: Option<String>,
+    image_url: Option<String>,
+    group: Option<String>,
+    note: Option<String>,
+    history: Vec<(Day, bool)>,
 }
 
 impl WordStatus {
+    /// Новое изучаемое слово, которое начинает изучаться с дня `start`
+    fn new_to_learn(
+        translation: String,
+        start: Day,
+        stats: TypingStats,
+        extras: WordExtras,
+    ) -> Self {
+        let WordExtras {
+            tags,
+            example,
+            image_url,
+            group,
+            note,
+            history,
+        } = extras;
+        WordStatus::ToLearn {
+            translation,
+            last_learn: start,
+            current_level: 0,
+            current_count: 0,
+            ease: DEFAULT_EASE,
+            consecutive_wrong: 0,
+            force_mode: None,
+            tags,
+            example,
+            image_url,
+            group,
+            note,
+            history,
+            stats,
+        }
+    }
+
+    /// Выученное слово
+    fn new_learned(translation: String, stats: TypingStats, extras: WordExtras) -> Self {
+        let WordExtras {
+            tags,
+            example,
+            image_url,
+            group,
+            note,
+            history,
+        } = extras;
+        WordStatus::Learned {
+            translation,
+            tags,
+            example,
+            image_url,
+            group,
+            note,
+            history,
+            stats,
+        }
+    }
+
+    /// Забирает необязательные данные слова, чтобы перенести их в другой статус
+    fn take_extras(&mut self) -> WordExtras {
+        use WordStatus::*;
+        match self {
+            KnowPreviously | TrashWord => WordExtras::default(),
+            ToLearn {
+                tags,
+                example,
+                image_url,
+                group,
+                note,
+                history,
+                ..
+            }
+            | Learned {
+                tags,
+                example,
+                image_url,
+                group,
+                note,
+                history,
+                ..
+            }
+            | Suspended {
+                tags,
+                example,
+                image_url,
+                group,
+                note,
+                history,
+                ..
+            } => WordExtras {
+                tags: std::mem::take(tags),
+                example: example.take(),
+                image_url: image_url.take(),
+                group: group.take(),
+                note: note.take(),
+                history: std::mem::take(history),
+            },
+        }
+    }
+
+    /// Приостанавливает изучение слова
+    fn suspend(&mut self) {
+        if let WordStatus::ToLearn {
+            translation,
+            last_learn,
+            current_level,
+            current_count,
+            ease,
+            force_mode,
+            stats,
+            ..
+        } = self
+        {
+            let translation = std::mem::take(translation);
+            let (last_learn, saved_level, saved_count, saved_ease, force_mode, stats) = (
+                *last_learn,
+                *current_level,
+                *current_count,
+                *ease,
+                *force_mode,
+                *stats,
+            );
+            let WordExtras {
+                tags,
+                example,
+                image_url,
+                group,
+                note,
+                history,
+            } = self.take_extras();
+            *self = WordStatus::Suspended {
+                translation,
+                last_learn,
+                saved_level,
+                saved_count,
+                saved_ease,
+                force_mode,
+                tags,
+                example,
+                image_url,
+                group,
+                note,
+                history,
+                stats,
+            };
+        }
+    }
+
+    /// Возвращает приостановленное слово к изучению с того же места
+    fn unsuspend(&mut self) {
+        if let WordStatus::Suspended {
+            translation,
+            last_learn,
+            saved_level,
+            saved_count,
+            saved_ease,
+            force_mode,
+            stats,
+            ..
+        } = self
+        {
+            let translation = std::mem::take(translation);
+            let (last_learn, current_level, current_count, ease, force_mode, stats) = (
+                *last_learn,
+                *saved_level,
+                *saved_count,
+                *saved_ease,
+                *force_mode,
+                *stats,
+            );
+            let WordExtras {
+                tags,
+                example,
+                image_url,
+                group,
+                note,
+                history,
+            } = self.take_extras();
+            *self = WordStatus::ToLearn {
+                translation,
+                last_learn,
+                current_level,
+                current_count,
+                ease,
+                consecutive_wrong: 0,
+                force_mode,
+                tags,
+                example,
+                image_url,
+                group,
+                note,
+                history,
+                stats,
+            };
+        }
+    }
+
+    /// Сразу считает слово выученным, сохраняя перевод, заметки и статистику
+    fn mark_learned(&mut self) {
+        self.unsuspend();
+        if let WordStatus::ToLearn {
+            translation, stats, ..
+        } = self
+        {
+            let (translation, stats) = (std::mem::take(translation), *stats);
+            let extras = self.take_extras();
+            *self = WordStatus::new_learned(translation, stats, extras);
+        }
+    }
+
+    /// Начинает изучение слова заново, как будто его добавили сегодня
+    fn reset_progress(&mut self, today: Day) {
+        use WordStatus::*;
+        self.unsuspend();
+        if let ToLearn {
+            translation, stats, ..
+        }
+        | Learned {
+            translation, stats, ..
+        } = self
+        {
+            let (translation, stats) = (std::mem::take(translation), *stats);
+            let extras = self.take_extras();
+            *self = WordStatus::new_to_learn(translation, today, stats, extras);
+        }
+    }
+
+    /// Возвращает true, если слово перешло на следующий уровень
     fn register_attempt(
         &mut self,
         correct: bool,
         today: Day,
         day_stats: &mut DayStatistics,
-        type_count: &[LearnType],
-    ) {
+        settings: &Settings,
+    ) -> bool {
         use WordStatus::*;
+        let mut level_up = false;
+        let type_count = settings.schedule_for(self);
         match self {
-            KnowPreviously | TrashWord | Learned { .. } => unreachable!(),
+            KnowPreviously | TrashWord | Learned { .. } | Suspended { .. } => {
+                // Статус мог измениться во время изучения, например через окно редактирования
+                err!();
+                return false;
+            }
             ToLearn {
                 stats,
                 last_learn,
                 translation,
                 current_level,
                 current_count,
+                ease,
+                consecutive_wrong,
+                history,
+                ..
             } => {
+                history.push((today, correct));
+                if history.len() > HISTORY_LEN {
+                    history.drain(..history.len() - HISTORY_LEN);
+                }
+
                 if correct {
                     stats.right += 1;
                     day_stats.attempts.right += 1;
+                    *consecutive_wrong = 0;
                 } else {
                     stats.wrong += 1;
                     day_stats.attempts.wrong += 1;
+                    *ease = (*ease - EASE_PENALTY).max(MIN_EASE);
+                    *consecutive_wrong = consecutive_wrong.saturating_add(1);
+
+                    // Слово, которое постоянно вводится неправильно, начинает изучаться заново
+                    if settings.max_wrong_before_reset != 0
+                        && *consecutive_wrong >= settings.max_wrong_before_reset
+                    {
+                        *current_level = 0;
+                        *current_count = 0;
+                        *consecutive_wrong = 0;
+                    }
                 }
 
                 if correct {
-                    for learn in type_count.iter().skip(*current_level as _) {
-                        if learn.can_learn_today(*last_learn, today) {
-                            if *current_count + 1 != learn.count {
+                    if let Some(learn) = LearnType::at_level(type_count, *current_level) {
+                        if learn.can_learn_today(*last_learn, today, *ease, *current_count) {
+                            if *current_count + 1 < learn.repeats() {
                                 *current_count += 1;
+                                // Следующее повторение отсчитывается от этого
+                                if learn.per_day {
+                                    *last_learn = today;
+                                }
                             } else {
                                 *last_learn = today;
                                 *current_level += 1;
                                 *current_count = 0;
+                                *ease += EASE_BONUS;
+                                level_up = true;
                             }
-                            break;
                         }
                     }
 
-                    if *current_level as usize == type_count.len() {
-                        *self = WordStatus::Learned {
-                            translation: translation.clone(),
-                            stats: *stats,
-                        };
+                    // Расписание темы может быть короче уровня слова, тогда слово тоже считается выученным
+                    if *current_level as usize >= type_count.len() {
+                        day_stats.words_learned_today += 1;
+                        let (translation, stats) = (std::mem::take(translation), *stats);
+                        let extras = self.take_extras();
+                        *self = WordStatus::new_learned(translation, stats, extras);
                     }
                 }
             }
         }
+        level_up
+    }
+
+    /// Попытка в режиме зубрёжки: обновляется только статистика, расписание слова не меняется
+    fn register_cram_attempt(&mut self, correct: bool, day_stats: &mut DayStatistics) {
+        if let WordStatus::ToLearn { stats, .. } = self {
+            if correct {
+                stats.right += 1;
+                day_stats.attempts.right += 1;
+            } else {
+                stats.wrong += 1;
+                day_stats.attempts.wrong += 1;
+            }
+        }
+    }
+
+    /// Насколько далеко продвинулось изучение, нужно чтобы при слиянии выбрать более изученный вариант
+    fn progress(&self) -> (u8, u8, u8) {
+        use WordStatus::*;
+        match self {
+            KnowPreviously | TrashWord => (0, 0, 0),
+            ToLearn {
+                current_level,
+                current_count,
+                ..
+            }
+            | Suspended {
+                saved_level: current_level,
+                saved_count: current_count,
+                ..
+            } => (1, *current_level, *current_count),
+            Learned { .. } => (2, 0, 0),
+        }
+    }
+
+    fn tags(&self) -> Option<&BTreeSet<String>> {
+        use WordStatus::*;
+        match self {
+            KnowPreviously | TrashWord => None,
+            ToLearn { tags, .. } | Learned { tags, .. } | Suspended { tags, .. } => Some(tags),
+        }
     }
 
     fn has_translation(&self, translation2: &str) -> bool {
         use WordStatus::*;
         match self {
             KnowPreviously | TrashWord => false,
-            ToLearn { translation, .. } | Learned { translation, .. } => {
-                translation == translation2
-            }
+            ToLearn { translation, .. }
+            | Learned { translation, .. }
+            | Suspended { translation, .. } => translation == translation2,
         }
     }
 
+    /// Показывать ли слово при вводе перевода: по уровню или по настройке слова
     fn has_hint(&self, type_count: &[LearnType]) -> bool {
         use WordStatus::*;
         match self {
-            KnowPreviously | TrashWord | Learned { .. } => false,
-            ToLearn { current_level, .. } => type_count
-                .get(*current_level as usize)
-                .map(|x| x.show_word)
-                .unwrap_or(false),
+            KnowPreviously | TrashWord | Learned { .. } | Suspended { .. } => false,
+            ToLearn {
+                current_level,
+                force_mode,
+                ..
+            } => force_mode.unwrap_or_else(|| {
+                LearnType::at_level(type_count, *current_level)
+                    .map(|x| x.show_word)
+                    .unwrap_or(false)
+            }),
         }
     }
 
@@ -194,30 +677,98 @@ impl WordStatus {
         if let WordStatus::ToLearn {
             last_learn,
             current_level,
+            current_count,
+            ease,
             ..
         } = self
         {
-            type_count
-                .get(*current_level as usize)
-                .map(|learn| learn.can_learn_today(*last_learn, today))
+            LearnType::at_level(type_count, *current_level)
+                .map(|learn| learn.can_learn_today(*last_learn, today, *ease, *current_count))
                 .unwrap_or(false)
         } else {
             false
         }
     }
 
+    /// Зрелость изучаемого слова, для остальных статусов None
+    fn maturity(&self, type_count: &[LearnType]) -> Option<Maturity> {
+        if let WordStatus::ToLearn {
+            current_level,
+            current_count,
+            ease,
+            ..
+        } = self
+        {
+            let interval = LearnType::at_level(type_count, *current_level)
+                .map(|learn| learn.wait_days_for(*ease, *current_count))
+                .unwrap_or(0);
+            Some(if interval < MATURE_INTERVAL_DAYS {
+                Maturity::Young
+            } else {
+                Maturity::Mature
+            })
+        } else {
+            None
+        }
+    }
+
     fn translation(&self) -> Option<&str> {
         use WordStatus::*;
-        if let ToLearn { translation, .. } | Learned { translation, .. } = self {
+        if let ToLearn { translation, .. }
+        | Learned { translation, .. }
+        | Suspended { translation, .. } = self
+        {
             Some(translation)
         } else {
             None
         }
     }
 
+    /// Группа форм, к которой относится этот перевод
+    fn group(&self) -> Option<&str> {
+        use WordStatus::*;
+        if let ToLearn { group, .. } | Learned { group, .. } | Suspended { group, .. } = self {
+            group.as_deref()
+        } else {
+            None
+        }
+    }
+
+    fn example(&self) -> Option<&str> {
+        use WordStatus::*;
+        if let ToLearn { example, .. } | Learned { example, .. } | Suspended { example, .. } = self
+        {
+            example.as_deref()
+        } else {
+            None
+        }
+    }
+
+    fn note(&self) -> Option<&str> {
+        use WordStatus::*;
+        if let ToLearn { note, .. } | Learned { note, .. } | Suspended { note, .. } = self {
+            note.as_deref()
+        } else {
+            None
+        }
+    }
+
+    fn history(&self) -> &[(Day, bool)] {
+        use WordStatus::*;
+        match self {
+            KnowPreviously | TrashWord => &[],
+            ToLearn { history, .. } | Learned { history, .. } | Suspended { history, .. } => {
+                history
+            }
+        }
+    }
+
     fn translation_mut(&mut self) -> Option<&mut String> {
         use WordStatus::*;
-        if let ToLearn { translation, .. } | Learned { translation, .. } = self {
+        if let ToLearn { translation, .. }
+        | Learned { translation, .. }
+        | Suspended { translation, .. } = self
+        {
             Some(translation)
         } else {
             None
@@ -238,10 +789,15 @@ impl WordStatus {
         if let ToLearn {
             last_learn,
             current_level,
+            current_count,
+            ease,
             ..
         } = self
         {
-            let date_to_learn = last_learn.0 + type_count[*current_level as usize].wait_days as u64;
+            let wait_days = LearnType::at_level(type_count, *current_level)
+                .map(|t| t.wait_days_for(*ease, *current_count))
+                .unwrap_or(0);
+            let date_to_learn = last_learn.0 + wait_days;
             if today.0 > date_to_learn {
                 0
             } else {
@@ -252,20 +808,39 @@ impl WordStatus {
         }
     }
 
+    fn next_due_date(&self, type_count: &[LearnType]) -> Option<Day> {
+        if let WordStatus::ToLearn {
+            last_learn,
+            current_level,
+            current_count,
+            ease,
+            ..
+        } = self
+        {
+            LearnType::at_level(type_count, *current_level)
+                .map(|learn| Day(last_learn.0 + learn.wait_days_for(*ease, *current_count)))
+        } else {
+            None
+        }
+    }
+
     fn attempts_remains(&self, today: Day, type_count: &[LearnType]) -> u8 {
         use WordStatus::*;
         if let ToLearn {
             last_learn,
             current_level,
             current_count,
+            ease,
             ..
         } = self
         {
-            if let Some(learn) = type_count.get(*current_level as usize) {
-                if learn.can_learn_today(*last_learn, today) {
-                    learn.count - current_count
-                } else {
+            if let Some(learn) = LearnType::at_level(type_count, *current_level) {
+                if !learn.can_learn_today(*last_learn, today, *ease, *current_count) {
                     0
+                } else if learn.per_day {
+                    1
+                } else {
+                    learn.repeats().saturating_sub(*current_count)
                 }
             } else {
                 0
@@ -276,19 +851,110 @@ impl WordStatus {
     }
 }
 
+/// Зерно, которое одинаково для слова в течение дня
+fn day_seed(word: &str, today: Day) -> u64 {
+    use std::hash::{Hash, Hasher};
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    word.hash(&mut hasher);
+    today.0.hash(&mut hasher);
+    hasher.finish()
+}
+
 /// Все слова в программе
 #[derive(Default, Serialize, Deserialize, Clone, Debug)]
 pub struct Words(BTreeMap<String, Vec<WordStatus>>);
 
+/// В каком направлении изучать слово: писать перевод по слову, слово по переводу или оба варианта
+#[derive(Clone, Copy, PartialEq, Default)]
+enum LearnDirection {
+    Forward,
+    Backward,
+    #[default]
+    Both,
+}
+
+/// Письменность, в которой записано слово
+#[derive(Clone, Copy, PartialEq, Debug)]
+enum Script {
+    Latin,
+    Cyrillic,
+    Cjk,
+    Unknown,
+}
+
+impl Script {
+    fn name(&self) -> &'static str {
+        match self {
+            Script::Latin => "Latin",
+            Script::Cyrillic => "Cyrillic",
+            Script::Cjk => "CJK",
+            Script::Unknown => "?",
+        }
+    }
+
+    /// Язык для произношения, если он не задан в настройках
+    fn default_speech_lang(&self) -> &'static str {
+        match self {
+            Script::Latin => "en",
+            Script::Cyrillic => "ru",
+            Script::Cjk => "zh",
+            Script::Unknown => "",
+        }
+    }
+
+    /// Вводить слова этой письменности с обычной клавиатуры неудобно
+    fn hard_to_type(&self) -> bool {
+        *self == Script::Cjk
+    }
+}
+
+/// Определяет письменность по большинству букв в строке
+fn detect_script(s: &str) -> Script {
+    let mut counts = [0usize; 3];
+    for c in s.chars() {
+        match c as u32 {
+            0x41..=0x5A | 0x61..=0x7A | 0xC0..=0x24F => counts[0] += 1,
+            0x400..=0x52F => counts[1] += 1,
+            0x3040..=0x30FF | 0x3400..=0x4DBF | 0x4E00..=0x9FFF | 0xAC00..=0xD7AF => counts[2] += 1,
+            _ => {}
+        }
+    }
+    let (pos, count) = counts
+        .iter()
+        .enumerate()
+        .max_by_key(|(_, count)| **count)
+        .unwrap();
+    match pos {
+        _ if *count == 0 => Script::Unknown,
+        0 => Script::Latin,
+        1 => Script::Cyrillic,
+        _ => Script::Cjk,
+    }
+}
+
+impl LearnDirection {
+    /// Не предлагает вводить слова, которые трудно набрать на клавиатуре
+    fn suggest(word: Script, translations: Script) -> Self {
+        match (word.hard_to_type(), translations.hard_to_type()) {
+            (true, false) => LearnDirection::Forward,
+            (false, true) => LearnDirection::Backward,
+            _ => LearnDirection::Both,
+        }
+    }
+}
+
 enum WordsToAdd {
     KnowPreviously,
     TrashWord,
     ToLearn {
         learned: Vec<String>,
         translations: Vec<String>,
+        direction: LearnDirection,
+        tags: BTreeSet<String>,
     },
 }
 
+#[derive(Clone, PartialEq)]
 struct WordsToLearn {
     known_words: Vec<String>,
     words_to_type: Vec<String>,
@@ -300,68 +966,216 @@ impl Words {
         self.0.iter().map(|(word, _)| word.clone()).collect()
     }
 
+    /// Возвращает количество пропущенных переводов, которые повторяются или уже есть у слова
     fn add_word(
         &mut self,
         word: String,
         info: WordsToAdd,
         today: Day,
         day_stats: &mut DayStatistics,
-    ) {
+        settings: &Settings,
+    ) -> usize {
         use WordsToAdd::*;
-        let entry = self.0.entry(word.clone()).or_insert_with(Vec::new);
         match info {
-            KnowPreviously => entry.push(WordStatus::KnowPreviously),
-            TrashWord => entry.push(WordStatus::TrashWord),
+            KnowPreviously => self
+                .0
+                .entry(word)
+                .or_default()
+                .push(WordStatus::KnowPreviously),
+            TrashWord => self.0.entry(word).or_default().push(WordStatus::TrashWord),
             ToLearn {
                 learned,
                 translations,
+                direction,
+                tags,
             } => {
-                for translation in &translations {
-                    entry.push(WordStatus::ToLearn {
-                        translation: translation.clone(),
-                        last_learn: today,
-                        current_level: 0,
-                        current_count: 0,
-                        stats: Default::default(),
-                    });
-                    day_stats.new_unknown_words_count += 1;
-                }
-                for translation in &learned {
-                    entry.push(WordStatus::Learned {
-                        translation: translation.clone(),
-                        stats: Default::default(),
-                    });
-                    day_stats.new_unknown_words_count += 1;
+                let start = self.first_learn_day(today, day_stats, settings);
+                let count = translations.len() + learned.len();
+                let mut seen = BTreeSet::new();
+                let mut is_new = |translation: &String| {
+                    !self.has_translation(&word, translation) && seen.insert(translation.clone())
+                };
+                let translations: Vec<String> =
+                    translations.into_iter().filter(&mut is_new).collect();
+                let learned: Vec<String> = learned.into_iter().filter(&mut is_new).collect();
+                let skipped = count - translations.len() - learned.len();
+                if start == today {
+                    day_stats.new_unknown_words_count +=
+                        (translations.len() + learned.len()) as u64;
                 }
-                for translation in translations {
-                    self.0
-                        .entry(translation)
-                        .or_insert_with(Vec::new)
-                        .push(WordStatus::ToLearn {
-                            translation: word.clone(),
-                            last_learn: today,
-                            current_level: 0,
-                            current_count: 0,
-                            stats: Default::default(),
-                        });
+                if direction != LearnDirection::Backward {
+                    let entry = self.0.entry(word.clone()).or_default();
+                    for translation in &translations {
+                        entry.push(WordStatus::new_to_learn(
+                            translation.clone(),
+                            start,
+                            Default::default(),
+                            WordExtras {
+                                tags: tags.clone(),
+                                ..Default::default()
+                            },
+                        ));
+                    }
+                    for translation in &learned {
+                        entry.push(WordStatus::new_learned(
+                            translation.clone(),
+                            Default::default(),
+                            WordExtras {
+                                tags: tags.clone(),
+                                ..Default::default()
+                            },
+                        ));
+                    }
+                }
+                if direction == LearnDirection::Forward {
+                    return skipped;
+                }
+                for translation in translations {
+                    self.0.entry(translation).or_insert_with(Vec::new).push(
+                        WordStatus::new_to_learn(
+                            word.clone(),
+                            start,
+                            Default::default(),
+                            WordExtras {
+                                tags: tags.clone(),
+                                ..Default::default()
+                            },
+                        ),
+                    );
                 }
                 for translation in learned {
-                    self.0
-                        .entry(translation)
-                        .or_insert_with(Vec::new)
-                        .push(WordStatus::Learned {
-                            translation: word.clone(),
-                            stats: Default::default(),
-                        });
+                    self.0.entry(translation).or_insert_with(Vec::new).push(
+                        WordStatus::new_learned(
+                            word.clone(),
+                            Default::default(),
+                            WordExtras {
+                                tags: tags.clone(),
+                                ..Default::default()
+                            },
+                        ),
+                    );
+                }
+                return skipped;
+            }
+        }
+        0
+    }
+
+    /// С какого дня начнётся изучение новых переводов. Если дневной лимит новых слов исчерпан, то изучение откладывается на ближайший день, на который отложено меньше слов, чем лимит.
+    fn first_learn_day(&self, today: Day, day_stats: &DayStatistics, settings: &Settings) -> Day {
+        if !settings.new_words_limit_reached(day_stats) {
+            return today;
+        }
+        let deferred = self.new_pairs_by_day(Day(today.0 + 1));
+        let mut day = Day(today.0 + 1);
+        while deferred.get(&day).copied().unwrap_or(0) >= settings.daily_new_limit {
+            day.0 += 1;
+        }
+        day
+    }
+
+    /// Сколько новых, ещё ни разу не изученных пар слово-перевод начинают изучаться в каждый день начиная с `from`. Пара в обе стороны считается один раз, как и в дневной статистике.
+    fn new_pairs_by_day(&self, from: Day) -> BTreeMap<Day, u64> {
+        let mut pairs: BTreeMap<Day, BTreeSet<(&str, &str)>> = BTreeMap::new();
+        for (word, statuses) in &self.0 {
+            for status in statuses {
+                if let WordStatus::ToLearn {
+                    translation,
+                    last_learn,
+                    current_level: 0,
+                    current_count: 0,
+                    ..
+                } = status
+                {
+                    if *last_learn >= from {
+                        let pair = if word.as_str() < translation.as_str() {
+                            (word.as_str(), translation.as_str())
+                        } else {
+                            (translation.as_str(), word.as_str())
+                        };
+                        pairs.entry(*last_learn).or_default().insert(pair);
+                    }
+                }
+            }
+        }
+        pairs
+            .into_iter()
+            .map(|(day, pairs)| (day, pairs.len() as u64))
+            .collect()
+    }
+
+    fn all_tags(&self) -> BTreeSet<String> {
+        self.0
+            .values()
+            .flatten()
+            .filter_map(|x| x.tags())
+            .flatten()
+            .cloned()
+            .collect()
+    }
+
+    /// Есть ли у слова хотя бы одна из тем. Если темы не выбраны, то подходит любое слово.
+    fn has_any_tag(&self, word: &str, tags: &BTreeSet<String>) -> bool {
+        tags.is_empty()
+            || self
+                .0
+                .get(word)
+                .map(|x| {
+                    x.iter()
+                        .filter_map(|x| x.tags())
+                        .any(|x| !x.is_disjoint(tags))
+                })
+                .unwrap_or(false)
+    }
+
+    /// Слово новое, если хотя бы один его перевод ещё на нулевом уровне
+    fn is_new(&self, word: &str) -> bool {
+        self.0
+            .get(word)
+            .map(|x| x.iter().any(|x| x.level() == Some(0)))
+            .unwrap_or(false)
+    }
+
+    /// Объединяет слова с другого устройства. Если перевод есть в обоих местах, остаётся более изученный вариант.
+    fn merge(&mut self, other: Words) {
+        for (word, statuses) in other.0 {
+            let entry = self.0.entry(word).or_default();
+            for status in statuses {
+                let existing = if let Some(translation) = status.translation() {
+                    entry.iter().position(|x| x.has_translation(translation))
+                } else {
+                    entry.iter().position(|x| {
+                        x.translation().is_none()
+                            && std::mem::discriminant(x) == std::mem::discriminant(&status)
+                    })
+                };
+                match existing {
+                    Some(position) => {
+                        if status.progress() > entry[position].progress() {
+                            entry[position] = status;
+                        }
+                    }
+                    None => entry.push(status),
                 }
             }
         }
     }
 
+    /// Есть ли у слова такой перевод. Слово, добавленное только в обратном направлении, хранится под своим переводом, поэтому проверяются оба ключа.
+    fn has_translation(&self, word: &str, translation: &str) -> bool {
+        let has = |word: &str, translation: &str| {
+            self.0
+                .get(word)
+                .map(|x| x.iter().any(|x| x.has_translation(translation)))
+                .unwrap_or(false)
+        };
+        has(word, translation) || has(translation, word)
+    }
+
     fn is_learned(&self, word: &str) -> bool {
         if let Some(word) = self.0.get(word) {
             for i in word {
-                if matches!(i, WordStatus::ToLearn { .. }) {
+                if matches!(i, WordStatus::ToLearn { .. } | WordStatus::Suspended { .. }) {
                     return false;
                 }
             }
@@ -372,47 +1186,64 @@ impl Words {
         }
     }
 
-    fn has_hint(&self, word: &str, type_count: &[LearnType]) -> bool {
+    fn has_hint(&self, word: &str, settings: &Settings) -> bool {
         if let Some(word) = self.0.get(word) {
-            word.iter().any(|x| x.has_hint(type_count))
+            word.iter().any(|x| x.has_hint(settings.schedule_for(x)))
         } else {
             false
         }
     }
 
-    fn get_word_to_learn(&self, word: &str, today: Day, type_count: &[LearnType]) -> WordsToLearn {
+    /// Какие переводы слова надо ввести сегодня. Если `bypass_schedule`, то все изучаемые переводы считаются доступными для изучения.
+    fn get_word_to_learn(
+        &self,
+        word: &str,
+        today: Day,
+        settings: &Settings,
+        bypass_schedule: bool,
+    ) -> WordsToLearn {
         let mut known_words = Vec::new();
         let mut words_to_type = Vec::new();
         let mut words_to_guess = Vec::new();
         for i in self.0.get(word).unwrap() {
+            let type_count = settings.schedule_for(i);
             if let WordStatus::ToLearn {
                 translation,
                 last_learn,
                 current_level,
+                current_count,
+                ease,
                 ..
             } = i
             {
-                for learn in type_count.iter().skip(*current_level as _) {
-                    if learn.can_learn_today(*last_learn, today) {
-                        if learn.show_word {
+                if bypass_schedule {
+                    if i.has_hint(type_count) {
+                        words_to_type.push(translation.clone());
+                    } else {
+                        words_to_guess.push(translation.clone());
+                    }
+                    continue;
+                }
+                match LearnType::at_level(type_count, *current_level) {
+                    Some(learn)
+                        if learn.can_learn_today(*last_learn, today, *ease, *current_count) =>
+                    {
+                        if i.has_hint(type_count) {
                             words_to_type.push(translation.clone());
                         } else {
                             words_to_guess.push(translation.clone());
                         }
-                        break;
                     }
-                }
-                if type_count
-                    .iter()
-                    .skip(*current_level as _)
-                    .all(|x| !x.can_learn_today(*last_learn, today))
-                {
-                    known_words.push(translation.clone());
+                    _ => known_words.push(translation.clone()),
                 }
             } else if let WordStatus::Learned { translation, .. } = i {
                 known_words.push(translation.clone());
             }
         }
+
+        // Порядок полей перемешивается, чтобы запоминался перевод, а не его место. В течение дня порядок не меняется, иначе слово считалось бы изменённым при каждой проверке
+        words_to_guess.shuffle(&mut Rand::seed_from_u64(day_seed(word, today)));
+
         WordsToLearn {
             known_words,
             words_to_type,
@@ -420,25 +1251,40 @@ impl Words {
         }
     }
 
+    /// Возвращает не более `review_limit` старых слов, самые просроченные идут первыми. Если `tags` не пусто, то только слова с этими темами.
     fn get_words_to_learn_today(
         &self,
         today: Day,
-        type_count: &[LearnType],
+        settings: &Settings,
+        review_limit: usize,
+        tags: &BTreeSet<String>,
+        maturity: Option<Maturity>,
     ) -> (Vec<String>, Vec<String>) {
         let mut new = Vec::new();
         let mut repeat = Vec::new();
         for (word, statuses) in &self.0 {
-            if statuses
-                .iter()
-                .any(|x| x.can_learn_today(today, type_count))
-            {
-                if statuses.iter().any(|x| x.level() == Some(0)) {
+            if !self.has_any_tag(word, tags) {
+                continue;
+            }
+            if statuses.iter().any(|x| {
+                let type_count = settings.schedule_for(x);
+                x.can_learn_today(today, type_count)
+                    && maturity
+                        .map(|m| x.maturity(type_count) == Some(m))
+                        .unwrap_or(true)
+            }) {
+                if self.is_new(word) {
                     new.push(word.clone());
                 } else {
                     repeat.push(word.clone());
                 }
             }
         }
+        if repeat.len() > review_limit {
+            // Чем раньше слово надо было повторить, тем сильнее оно просрочено
+            repeat.sort_by_cached_key(|x| self.next_due_date(x, settings));
+            repeat.truncate(review_limit);
+        }
         (repeat, new)
     }
 
@@ -449,12 +1295,36 @@ impl Words {
         correct: bool,
         today: Day,
         day_stats: &mut DayStatistics,
-        type_count: &[LearnType],
+        settings: &Settings,
+    ) -> bool {
+        if let Some(statuses) = self.0.get_mut(word) {
+            for i in statuses {
+                if i.has_translation(translation) {
+                    let level_up = i.register_attempt(correct, today, day_stats, settings);
+                    if level_up {
+                        day_stats.words_leveled.push(word.to_owned());
+                    }
+                    return level_up;
+                }
+            }
+            err!();
+        } else {
+            err!();
+        }
+        false
+    }
+
+    fn register_cram_attempt(
+        &mut self,
+        word: &str,
+        translation: &str,
+        correct: bool,
+        day_stats: &mut DayStatistics,
     ) {
         if let Some(word) = self.0.get_mut(word) {
             for i in word {
                 if i.has_translation(translation) {
-                    i.register_attempt(correct, today, day_stats, type_count);
+                    i.register_cram_attempt(correct, day_stats);
                     return;
                 }
             }
@@ -464,6 +1334,35 @@ impl Words {
         }
     }
 
+    /// Насколько сложное слово: отношение неправильных ответов к правильным
+    fn difficulty(&self, word: &str) -> f64 {
+        let mut result = TypingStats::default();
+        for i in self.0.get(word).into_iter().flatten() {
+            if let WordStatus::ToLearn { stats, .. } = i {
+                result.right += stats.right;
+                result.wrong += stats.wrong;
+            }
+        }
+        (result.wrong + 1) as f64 / (result.right + 1) as f64
+    }
+
+    /// Сколько раз был дан неправильный ответ для этого перевода
+    fn wrong_attempts(&self, word: &str, translation: &str) -> u64 {
+        self.0
+            .get(word)
+            .into_iter()
+            .flatten()
+            .find_map(|x| match x {
+                WordStatus::ToLearn {
+                    translation: t,
+                    stats,
+                    ..
+                } if t == translation => Some(stats.wrong),
+                _ => None,
+            })
+            .unwrap_or(0)
+    }
+
     fn calculate_word_statistics(&self) -> BTreeMap<WordType, u64> {
         let mut result = BTreeMap::new();
         for i in self.0.values().flatten() {
@@ -475,11 +1374,25 @@ impl Words {
                     *result.entry(WordType::Level(*current_level)).or_insert(0) += 1
                 }
                 Learned { .. } => *result.entry(WordType::Learned).or_insert(0) += 1,
+                Suspended { .. } => *result.entry(WordType::Suspended).or_insert(0) += 1,
             }
         }
         result
     }
 
+    fn calculate_maturity_statistics(&self, settings: &Settings) -> BTreeMap<Maturity, u64> {
+        let mut result = BTreeMap::new();
+        for maturity in self
+            .0
+            .values()
+            .flatten()
+            .filter_map(|x| x.maturity(settings.schedule_for(x)))
+        {
+            *result.entry(maturity).or_insert(0) += 1;
+        }
+        result
+    }
+
     fn calculate_attempts_statistics(&self) -> TypingStats {
         let mut result = TypingStats::default();
         for i in self.0.values().flatten() {
@@ -491,6 +1404,59 @@ impl Words {
         result
     }
 
+    /// Статистика написаний изучаемых слов, сгруппированная по текущему уровню
+    fn accuracy_by_level(&self) -> BTreeMap<u8, TypingStats> {
+        let mut result: BTreeMap<u8, TypingStats> = BTreeMap::new();
+        for i in self.0.values().flatten() {
+            if let WordStatus::ToLearn {
+                stats,
+                current_level,
+                ..
+            } = i
+            {
+                let level = result.entry(*current_level).or_default();
+                level.right += stats.right;
+                level.wrong += stats.wrong;
+            }
+        }
+        result
+    }
+
+    /// Все слова вместе с их статусами, в алфавитном порядке
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &[WordStatus])> {
+        self.0
+            .iter()
+            .map(|(word, statuses)| (word.as_str(), statuses.as_slice()))
+    }
+
+    pub fn get(&self, word: &str) -> Option<&[WordStatus]> {
+        self.0.get(word).map(|x| x.as_slice())
+    }
+
+    pub fn contains(&self, word: &str) -> bool {
+        self.0.contains_key(word)
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Удаляет все мусорные слова и возвращает их количество. У мусорных слов нет переводов, поэтому обратные ссылки не затрагиваются
+    fn purge_trash(&mut self) -> usize {
+        let mut removed = 0;
+        for statuses in self.0.values_mut() {
+            let before = statuses.len();
+            statuses.retain(|x| !matches!(x, WordStatus::TrashWord));
+            removed += before - statuses.len();
+        }
+        self.0.retain(|_, statuses| !statuses.is_empty());
+        removed
+    }
+
     fn remove_word(&mut self, word: &str) {
         let translations: Vec<String> = self
             .0
@@ -508,14 +1474,34 @@ impl Words {
                     .cloned()
                     .collect();
             }
-            if self.0.get(word).map(|x| x.is_empty()).unwrap_or(false) {
+            if self
+                .0
+                .get(&translation)
+                .map(|x| x.is_empty())
+                .unwrap_or(false)
+            {
                 self.0.remove(&translation);
             }
         }
     }
 
-    fn rename_word(&mut self, word: &str, new_word: &str) {
-        let status = self.0.remove(word).unwrap();
+    /// Заменяет все статусы слова на один, например помечает слово как мусорное
+    fn set_status(&mut self, word: &str, status: WordStatus) {
+        if self.0.contains_key(word) {
+            self.remove_word(word);
+        }
+        self.0.insert(word.to_owned(), vec![status]);
+    }
+
+    /// Переименовывает слово вместе с обратными ссылками. Если новое слово уже есть, то ничего не меняется и возвращается ошибка.
+    fn rename_word(&mut self, word: &str, new_word: &str) -> Result<(), String> {
+        if word != new_word && self.0.contains_key(new_word) {
+            return Err(format!("Word '{}' already exists", new_word));
+        }
+        let status = match self.0.remove(word) {
+            Some(status) => status,
+            None => return Ok(()),
+        };
         let translations: Vec<String> = status
             .iter()
             .filter_map(|x| x.translation().map(|x| x.to_owned()))
@@ -538,12 +1524,13 @@ impl Words {
                     .collect();
             }
         }
+        Ok(())
     }
 
-    fn max_overdue_days(&self, word: &str, today: Day, type_count: &[LearnType]) -> u64 {
+    fn max_overdue_days(&self, word: &str, today: Day, settings: &Settings) -> u64 {
         if let Some(trs) = self.0.get(word) {
             trs.iter()
-                .map(|x| x.overdue_days(today, type_count))
+                .map(|x| x.overdue_days(today, settings.schedule_for(x)))
                 .max()
                 .unwrap_or(0)
         } else {
@@ -551,10 +1538,38 @@ impl Words {
         }
     }
 
-    fn max_attempts_remains(&self, word: &str, today: Day, type_count: &[LearnType]) -> u8 {
+    /// Все группы форм, в которые входит слово
+    fn groups_of(&self, word: &str) -> BTreeSet<&str> {
+        self.0
+            .get(word)
+            .into_iter()
+            .flatten()
+            .filter_map(|x| x.group())
+            .collect()
+    }
+
+    /// Все слова из группы форм, в алфавитном порядке
+    fn group_members(&self, group: &str) -> Vec<&str> {
+        self.0
+            .iter()
+            .filter(|(_, statuses)| statuses.iter().any(|x| x.group() == Some(group)))
+            .map(|(word, _)| word.as_str())
+            .collect()
+    }
+
+    /// Ближайший день, когда это слово снова можно будет повторять. Если все переводы выучены, то None.
+    fn next_due_date(&self, word: &str, settings: &Settings) -> Option<Day> {
+        self.0
+            .get(word)?
+            .iter()
+            .filter_map(|x| x.next_due_date(settings.schedule_for(x)))
+            .min()
+    }
+
+    fn max_attempts_remains(&self, word: &str, today: Day, settings: &Settings) -> u8 {
         if let Some(trs) = self.0.get(word) {
             trs.iter()
-                .map(|x| x.attempts_remains(today, type_count))
+                .map(|x| x.attempts_remains(today, settings.schedule_for(x)))
                 .max()
                 .unwrap_or(0)
         } else {
@@ -562,445 +1577,2019 @@ impl Words {
         }
     }
 
-    fn can_learn_today(&self, word: &str, today: Day, type_count: &[LearnType]) -> bool {
+    fn can_learn_today(&self, word: &str, today: Day, settings: &Settings) -> bool {
         self.0
             .get(word)
-            .map(|x| x.iter().any(|x| x.can_learn_today(today, type_count)))
+            .map(|x| {
+                x.iter()
+                    .any(|x| x.can_learn_today(today, settings.schedule_for(x)))
+            })
             .unwrap_or(false)
     }
+
+    /// Сколько переводов придётся повторять в каждый из ближайших `horizon` дней, если на все отвечать правильно
+    fn projected_reviews(
+        &self,
+        today: Day,
+        horizon: u32,
+        settings: &Settings,
+    ) -> BTreeMap<Day, u64> {
+        let last_day = today.0 + horizon as u64;
+        let mut result: BTreeMap<Day, u64> = (today.0..=last_day).map(|x| (Day(x), 0)).collect();
+        for status in self.0.values().flatten() {
+            let type_count = settings.schedule_for(status);
+            if let WordStatus::ToLearn {
+                last_learn,
+                current_level,
+                current_count,
+                ease,
+                ..
+            } = status
+            {
+                let mut last_learn = *last_learn;
+                let mut level = (*current_level as usize).min(type_count.len().saturating_sub(1));
+                let mut count = *current_count;
+                for day in today.0..=last_day {
+                    match type_count.get(level) {
+                        Some(learn)
+                            if learn.can_learn_today(last_learn, Day(day), *ease, count) =>
+                        {
+                            *result.get_mut(&Day(day)).unwrap() += 1;
+                            last_learn = Day(day);
+                            // Без `per_day` все повторения уровня делаются за один день
+                            if learn.per_day && count + 1 < learn.repeats() {
+                                count += 1;
+                            } else {
+                                level += 1;
+                                count = 0;
+                            }
+                        }
+                        Some(_) => {}
+                        None => break,
+                    }
+                }
+            }
+        }
+        result
+    }
 }
 
-fn get_words_subtitles(subtitles: &str) -> Result<GetWordsResult, srtparse::ReaderError> {
-    let subtitles = srtparse::from_str(subtitles)?;
-    let text = subtitles
-        .into_iter()
-        .map(|x| x.text)
-        .collect::<Vec<_>>()
-        .join("\n");
+/// Пары слово-перевод в формате TSV для импорта в Anki. Обратные пары выводятся только один раз.
+fn words_to_anki_tsv(words: &Words) -> String {
+    fn clean(text: &str) -> String {
+        text.replace(['\t', '\n', '\r'], " ")
+    }
 
-    Ok(get_words(&text))
+    let mut written = BTreeSet::new();
+    let mut result = String::new();
+    for (word, statuses) in &words.0 {
+        for translation in statuses.iter().filter_map(|x| x.translation()) {
+            if written.contains(&(translation, word.as_str())) {
+                continue;
+            }
+            if written.insert((word.as_str(), translation)) {
+                result.push_str(&clean(word));
+                result.push('\t');
+                result.push_str(&clean(translation));
+                result.push('\n');
+            }
+        }
+    }
+    result
 }
 
-struct WordsWithContext(Vec<(String, Vec<std::ops::Range<usize>>)>);
-
-struct GetWordsResult {
-    text: String,
-    words_with_context: WordsWithContext,
-    words_count: usize,
-    unique_words_count: usize,
+/// Какие слова попадают в лист для печати
+enum WordFilter<'a> {
+    /// Слова, которые сегодня надо повторять
+    Due {
+        today: Day,
+        settings: &'a Settings,
+    },
+    Selected(&'a BTreeSet<String>),
 }
 
-fn is_word_symbol(c: char) -> bool {
-    c.is_alphabetic() || c == '\'' || c == '-'
+/// Лист карточек для печати: слово с примером слева, переводы и заметки справа, чтобы правую колонку можно было загнуть
+fn words_to_html(words: &Words, filter: WordFilter) -> String {
+    fn escape(text: &str) -> String {
+        text.replace('&', "&amp;")
+            .replace('<', "&lt;")
+            .replace('>', "&gt;")
+            .replace('"', "&quot;")
+    }
+
+    let mut result = String::from(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>Flashcards</title>\n<style>\ntable { border-collapse: collapse; width: 100%; }\ntd { border: 1px solid #888; padding: 6px; vertical-align: top; width: 50%; }\n.example { font-style: italic; color: #555; }\n.note { color: #555; }\n</style>\n</head>\n<body>\n<table>\n",
+    );
+    for (word, statuses) in &words.0 {
+        let included = match &filter {
+            WordFilter::Due { today, settings } => words.can_learn_today(word, *today, settings),
+            WordFilter::Selected(selected) => selected.contains(word),
+        };
+        let translations: Vec<&str> = statuses.iter().filter_map(|x| x.translation()).collect();
+        if !included || translations.is_empty() {
+            continue;
+        }
+        result.push_str("<tr><td><b>");
+        result.push_str(&escape(word));
+        result.push_str("</b>");
+        for example in statuses.iter().filter_map(|x| x.example()) {
+            result.push_str(&format!("<div class=\"example\">{}</div>", escape(example)));
+        }
+        result.push_str("</td><td>");
+        result.push_str(&escape(&translations.join(", ")));
+        for note in statuses.iter().filter_map(|x| x.note()) {
+            result.push_str(&format!("<div class=\"note\">{}</div>", escape(note)));
+        }
+        result.push_str("</td></tr>\n");
+    }
+    result.push_str("</table>\n</body>\n</html>\n");
+    result
 }
 
-fn get_words(text: &str) -> GetWordsResult {
-    let mut words_count = 0;
-    let mut words = BTreeMap::new();
-    let mut current_word: Option<(String, usize)> = None;
-    for (i, c) in text
-        .char_indices()
-        .chain(std::iter::once((text.len(), '.')))
-    {
-        if is_word_symbol(c) {
-            if let Some((word, _)) = &mut current_word {
-                *word += &c.to_lowercase().collect::<String>();
+/// Разбивает строку CSV или TSV на поля. Поля могут быть в кавычках, `""` внутри кавычек означает одну кавычку.
+fn split_delimited_line(line: &str, delimiter: char) -> Vec<String> {
+    let mut result = Vec::new();
+    let mut current = String::new();
+    let mut in_quotes = false;
+    let mut chars = line.chars().peekable();
+    while let Some(c) = chars.next() {
+        if in_quotes {
+            if c == '"' {
+                if chars.peek() == Some(&'"') {
+                    chars.next();
+                    current.push('"');
+                } else {
+                    in_quotes = false;
+                }
             } else {
-                current_word = Some((c.to_lowercase().collect(), i));
+                current.push(c);
             }
-        } else if let Some((word, start)) = &mut current_word {
-            words_count += 1;
-            words
-                .entry(word.clone())
-                .or_insert_with(Vec::new)
-                .push(*start..i);
-            current_word = None;
+        } else if c == '"' && current.trim().is_empty() {
+            current.clear();
+            in_quotes = true;
+        } else if c == delimiter {
+            result.push(std::mem::take(&mut current));
+        } else {
+            current.push(c);
         }
     }
-    let mut words: Vec<_> = words.into_iter().collect();
-
-    words.sort_by_key(|x| std::cmp::Reverse(x.1.len()));
-
-    let unique_words_count = words.len();
+    result.push(current);
+    result
+}
 
-    GetWordsResult {
-        text: text.to_owned(),
-        words_with_context: WordsWithContext(words),
-        words_count,
-        unique_words_count,
+/// Слова из файла, где в первой колонке слово, а во второй его перевод, например из экспорта Anki. Остальные колонки игнорируются. Вторым значением возвращаются номера строк, которые не удалось разобрать.
+fn words_from_delimited(text: &str, delimiter: char) -> (Vec<(String, WordsToAdd)>, Vec<usize>) {
+    let mut result = Vec::new();
+    let mut failed = Vec::new();
+    for (number, line) in text.lines().enumerate() {
+        // Anki пишет в начало файла строки вида `#separator:tab`
+        if line.trim().is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let fields = split_delimited_line(line, delimiter);
+        let word = normalize_phrase(&fields[0]);
+        let translation = fields
+            .get(1)
+            .map(|x| normalize_phrase(x))
+            .unwrap_or_default();
+        if word.is_empty() || translation.is_empty() {
+            failed.push(number + 1);
+            continue;
+        }
+        result.push((
+            word,
+            WordsToAdd::ToLearn {
+                learned: Vec::new(),
+                translations: vec![translation],
+                direction: LearnDirection::Both,
+                tags: BTreeSet::new(),
+            },
+        ));
     }
+    (result, failed)
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug)]
-pub struct Settings {
-    type_count: Vec<LearnType>,
-    time_to_pause: f64,
-    use_keyboard_layout: bool,
-    keyboard_layout: KeyboardLayout,
-    dpi: f32,
-    #[serde(default)]
-    white_theme: bool,
+/// Текст всех субтитров без тегов, по строке на субтитр
+fn subtitles_text(subtitles: &str) -> Result<String, srtparse::ReaderError> {
+    let subtitles = srtparse::from_str(subtitles)?;
+    Ok(subtitles
+        .into_iter()
+        .map(|x| clean_subtitle_text(&x.text))
+        .collect::<Vec<_>>()
+        .join("\n"))
 }
 
-#[derive(Default, Serialize, Deserialize, Clone, Debug)]
-struct KeyboardLayout {
-    lang1: BTreeMap<char, char>,
-    lang2: BTreeMap<char, char>,
+/// Удаляет из текста субтитров теги вида `<i>`, `<font color=...>`, `<00:00:01.000>` и ASS-вставки вида `{\an8}`
+fn clean_subtitle_text(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut tag_end = None;
+    for c in text.chars() {
+        match (c, tag_end) {
+            ('<', None) => tag_end = Some('>'),
+            ('{', None) => tag_end = Some('}'),
+            (c, Some(end)) if c == end => tag_end = None,
+            (c, None) => result.push(c),
+            _ => {}
+        }
+    }
+    result
 }
 
-impl KeyboardLayout {
-    fn new(lang1: &str, lang2: &str) -> Result<KeyboardLayout, String> {
-        let a: Vec<char> = lang1.chars().filter(|x| *x != '\n').collect();
-        let b: Vec<char> = lang2.chars().filter(|x| *x != '\n').collect();
-        if a.len() != b.len() {
-            return Err(format!(
-                "Lengths of symbols are not equal: {} ≠ {}",
-                a.len(),
-                b.len()
-            ));
-        }
+/// Текст всех реплик WebVTT без тегов и служебных блоков
+fn vtt_text(subtitles: &str) -> Result<String, String> {
+    let mut lines = subtitles.lines();
+    if !lines
+        .next()
+        .map(|x| x.trim_start_matches('\u{feff}').starts_with("WEBVTT"))
+        .unwrap_or(false)
+    {
+        return Err("WebVTT file must start with the `WEBVTT` line".to_string());
+    }
 
-        let mut error_reason = (' ', ' ');
-        if a.iter().filter(|a| **a != ' ').any(|a| {
-            b.iter().any(|x| {
-                let result = *x == *a;
-                if result {
-                    error_reason = (*x, *a);
-                }
-                result
-            })
-        }) {
-            return Err(format!("In first lang there is symbol '{}', which equals to symbol '{}' in the second lang.", error_reason.0, error_reason.1));
+    let mut cues = Vec::new();
+    let mut block: Vec<&str> = Vec::new();
+    for line in lines.chain(std::iter::once("")) {
+        if !line.trim().is_empty() {
+            block.push(line);
+            continue;
+        }
+        // Блоки без времени — это заголовок, NOTE, STYLE или REGION, их пропускаем
+        if let Some(timing) = block.iter().position(|x| x.contains("-->")) {
+            cues.push(
+                block[timing + 1..]
+                    .iter()
+                    .map(|x| clean_subtitle_text(x))
+                    .collect::<Vec<_>>()
+                    .join("\n"),
+            );
         }
+        block.clear();
+    }
 
-        let mut result = Self {
-            lang1: Default::default(),
-            lang2: Default::default(),
-        };
+    Ok(cues.join("\n"))
+}
+
+/// Язык, для которого известны правила отбрасывания окончаний
+#[derive(Clone, Copy, PartialEq, Debug)]
+enum Lang {
+    English,
+    Russian,
+}
 
-        for (a, b) in a.iter().zip(b.iter()) {
-            result.lang1.insert(*a, *b);
-            result.lang2.insert(*b, *a);
+impl Lang {
+    fn name(&self) -> &'static str {
+        match self {
+            Lang::English => "English",
+            Lang::Russian => "Russian",
         }
+    }
+}
 
-        Ok(result)
+/// Основа слова, чтобы разные формы одного слова совпадали. Неправильные формы, как `ran` у `run`, не распознаются.
+fn stem(word: &str, lang: Lang) -> String {
+    match lang {
+        Lang::English => stem_english(word),
+        Lang::Russian => stem_russian(word),
     }
+}
 
-    fn change(&self, should_be: &str, to_change: &mut String) {
-        let is_first_lang = self.lang2.contains_key(&should_be.chars().next().unwrap());
-        let lang = if is_first_lang {
-            &self.lang1
-        } else {
-            &self.lang2
-        };
-        *to_change = to_change
-            .chars()
-            .map(|x| {
-                if let Some(c) = lang.get(&x).filter(|_| x != ' ') {
-                    *c
-                } else {
-                    x
-                }
-            })
-            .collect();
+/// Алгоритм Портера. Слова не из строчных латинских букв остаются как есть.
+fn stem_english(word: &str) -> String {
+    if word.len() <= 2 || !word.bytes().all(|c| c.is_ascii_lowercase()) {
+        return word.to_string();
     }
-}
 
-impl Default for Settings {
-    fn default() -> Self {
-        Settings {
-            type_count: vec![
-                LearnType::show(0, 2),
-                LearnType::guess(0, 3),
-                LearnType::guess(2, 3),
-                LearnType::guess(7, 2),
-                LearnType::guess(20, 2),
-            ],
-            time_to_pause: 15.,
-            use_keyboard_layout: false,
-            keyboard_layout: Default::default(),
-            dpi: 1.0,
-            white_theme: false,
+    fn is_consonant(b: &[u8], i: usize) -> bool {
+        match b[i] {
+            b'a' | b'e' | b'i' | b'o' | b'u' => false,
+            b'y' => i == 0 || !is_consonant(b, i - 1),
+            _ => true,
         }
     }
-}
-
-impl Settings {
-    fn color_github_zero(&self) -> egui::Color32 {
-        if self.white_theme {
-            egui::Color32::from_gray(240)
-        } else {
-            egui::Color32::from_gray(24)
+    /// Количество последовательностей «гласные, затем согласные» в основе
+    fn measure(b: &[u8]) -> usize {
+        let mut result = 0;
+        let mut previous_vowel = false;
+        for i in 0..b.len() {
+            let vowel = !is_consonant(b, i);
+            if previous_vowel && !vowel {
+                result += 1;
+            }
+            previous_vowel = vowel;
         }
+        result
     }
-
-    fn color_github_high(&self) -> egui::Color32 {
-        if self.white_theme {
-            egui::Color32::from_rgb(33, 110, 57)
-        } else {
-            egui::Color32::from_rgba_unmultiplied(0, 255, 128, 255)
-        }
+    fn has_vowel(b: &[u8]) -> bool {
+        (0..b.len()).any(|i| !is_consonant(b, i))
     }
-
-    fn color_github_low(&self) -> egui::Color32 {
-        if self.white_theme {
-            egui::Color32::from_rgb(155, 233, 168)
-        } else {
-            egui::Color32::from_rgba_unmultiplied(5, 101, 5, 255)
-        }
+    fn double_consonant(b: &[u8]) -> bool {
+        let n = b.len();
+        n >= 2 && b[n - 1] == b[n - 2] && is_consonant(b, n - 1)
     }
-
-    fn color_github_month(&self) -> egui::Color32 {
-        if self.white_theme {
-            egui::Color32::from_rgba_unmultiplied(76, 76, 76, 255)
-        } else {
-            egui::Color32::WHITE
-        }
+    /// Основа заканчивается на согласную, гласную и согласную, кроме w, x и y
+    fn cvc(b: &[u8]) -> bool {
+        let n = b.len();
+        n >= 3
+            && is_consonant(b, n - 3)
+            && !is_consonant(b, n - 2)
+            && is_consonant(b, n - 1)
+            && !matches!(b[n - 1], b'w' | b'x' | b'y')
     }
-
-    fn color_github_year(&self) -> egui::Color32 {
-        if self.white_theme {
-            egui::Color32::from_rgba_unmultiplied(226, 31, 31, 255)
-        } else {
-            egui::Color32::RED
+    /// Заменяет первое подходящее окончание, если основа достаточно длинная
+    fn replace(b: &mut Vec<u8>, rules: &[(&str, &str)], min_measure: usize) {
+        if let Some((from, to)) = rules.iter().find(|(from, _)| b.ends_with(from.as_bytes())) {
+            let stem_len = b.len() - from.len();
+            if measure(&b[..stem_len]) > min_measure {
+                b.truncate(stem_len);
+                b.extend(to.bytes());
+            }
         }
     }
 
-    fn color_delete(&self) -> egui::Color32 {
-        if self.white_theme {
-            egui::Color32::from_rgba_unmultiplied(213, 0, 0, 255)
-        } else {
-            egui::Color32::RED
-        }
+    let mut b = word.as_bytes().to_vec();
+
+    // Шаг 1a: множественное число
+    if b.ends_with(b"sses") || b.ends_with(b"ies") {
+        b.truncate(b.len() - 2);
+    } else if b.ends_with(b"s") && !b.ends_with(b"ss") {
+        b.pop();
     }
 
-    fn color_add(&self) -> egui::Color32 {
-        if self.white_theme {
-            egui::Color32::from_rgba_unmultiplied(0, 171, 0, 255)
-        } else {
-            egui::Color32::RED
+    // Шаг 1b: -ed и -ing
+    if b.ends_with(b"eed") {
+        if measure(&b[..b.len() - 3]) > 0 {
+            b.pop();
+        }
+    } else {
+        let suffix = [&b"ed"[..], b"ing"]
+            .into_iter()
+            .find(|x| b.ends_with(x) && has_vowel(&b[..b.len() - x.len()]));
+        if let Some(suffix) = suffix {
+            b.truncate(b.len() - suffix.len());
+            if b.ends_with(b"at") || b.ends_with(b"bl") || b.ends_with(b"iz") {
+                b.push(b'e');
+            } else if double_consonant(&b) && !matches!(b[b.len() - 1], b'l' | b's' | b'z') {
+                b.pop();
+            } else if measure(&b) == 1 && cvc(&b) {
+                b.push(b'e');
+            }
         }
     }
 
-    fn color_error(&self) -> egui::Color32 {
-        if self.white_theme {
-            egui::Color32::from_rgba_unmultiplied(255, 0, 0, 255)
-        } else {
-            egui::Color32::RED
-        }
+    // Шаг 1c
+    if b.ends_with(b"y") && has_vowel(&b[..b.len() - 1]) {
+        *b.last_mut().unwrap() = b'i';
     }
 
-    fn color_red_field_1(&self) -> egui::Color32 {
-        if self.white_theme {
-            egui::Color32::from_rgba_unmultiplied(255, 0, 0, 255)
-        } else {
-            egui::Color32::RED
-        }
+    #[rustfmt::skip]
+    replace(&mut b, &[
+        ("ational", "ate"), ("tional", "tion"), ("enci", "ence"), ("anci", "ance"),
+        ("izer", "ize"), ("bli", "ble"), ("alli", "al"), ("entli", "ent"), ("eli", "e"),
+        ("ousli", "ous"), ("ization", "ize"), ("ation", "ate"), ("ator", "ate"),
+        ("alism", "al"), ("iveness", "ive"), ("fulness", "ful"), ("ousness", "ous"),
+        ("aliti", "al"), ("iviti", "ive"), ("biliti", "ble"), ("logi", "log"),
+    ], 0);
+
+    #[rustfmt::skip]
+    replace(&mut b, &[
+        ("icate", "ic"), ("ative", ""), ("alize", "al"), ("iciti", "ic"), ("ical", "ic"),
+        ("ful", ""), ("ness", ""),
+    ], 0);
+
+    // Шаг 4: окончание -ion убирается только после s или t
+    if b.ends_with(b"ion") {
+        let stem_len = b.len() - 3;
+        if stem_len > 0 && matches!(b[stem_len - 1], b's' | b't') && measure(&b[..stem_len]) > 1 {
+            b.truncate(stem_len);
+        }
+    } else {
+        #[rustfmt::skip]
+        replace(&mut b, &[
+            ("al", ""), ("ance", ""), ("ence", ""), ("er", ""), ("ic", ""), ("able", ""),
+            ("ible", ""), ("ant", ""), ("ement", ""), ("ment", ""), ("ent", ""), ("ou", ""),
+            ("ism", ""), ("ate", ""), ("iti", ""), ("ous", ""), ("ive", ""), ("ize", ""),
+        ], 1);
     }
 
-    fn color_red_field_2(&self) -> egui::Color32 {
-        if self.white_theme {
-            egui::Color32::from_rgba_unmultiplied(224, 0, 0, 200)
-        } else {
-            egui::Color32::from_rgb_additive(128, 0, 0)
+    // Шаг 5
+    if b.ends_with(b"e") {
+        let stem = &b[..b.len() - 1];
+        let m = measure(stem);
+        if m > 1 || (m == 1 && !cvc(stem)) {
+            b.pop();
         }
     }
+    if b.ends_with(b"ll") && measure(&b) > 1 {
+        b.pop();
+    }
 
-    fn color_red_field_3(&self) -> egui::Color32 {
-        if self.white_theme {
-            egui::Color32::from_rgba_unmultiplied(255, 128, 128, 255)
-        } else {
-            egui::Color32::from_rgb_additive(255, 128, 128)
-        }
+    String::from_utf8(b).unwrap()
+}
+
+/// Упрощённое отбрасывание окончаний: убирается самое длинное известное окончание, если остаётся хотя бы три буквы
+fn stem_russian(word: &str) -> String {
+    const ENDINGS: &str = "\
+        ами ями ого его ому ему ыми ими ешь ете ила ило или ая яя ое ее ые ие ый ий ой ую юю \
+        ов ев ей ам ям ах ях ом ем ть ет ут ют ит ат ят ил а я о е ы и у ю ь";
+    let word = word.replace('ё', "е");
+    let ending = ENDINGS
+        .split_whitespace()
+        .filter(|x| word.ends_with(x) && word.chars().count() - x.chars().count() >= 3)
+        .max_by_key(|x| x.chars().count());
+    match ending {
+        Some(ending) => word[..word.len() - ending.len()].to_string(),
+        None => word,
     }
+}
 
-    fn color_green_field_1(&self) -> egui::Color32 {
-        if self.white_theme {
-            egui::Color32::from_rgba_unmultiplied(0, 255, 0, 255)
-        } else {
-            egui::Color32::GREEN
+struct WordsWithContext(Vec<(String, Vec<std::ops::Range<usize>>)>);
+
+impl WordsWithContext {
+    /// Объединяет формы одного слова под самой частой из них. Возвращает, сколько слов стало меньше.
+    fn merge_forms(&mut self, lang: Lang) -> usize {
+        let before = self.0.len();
+        let mut by_stem: BTreeMap<String, (String, Vec<std::ops::Range<usize>>, usize)> =
+            BTreeMap::new();
+        for (word, ranges) in self.0.drain(..) {
+            let entry = by_stem
+                .entry(stem(&word, lang))
+                .or_insert_with(|| (word.clone(), Vec::new(), 0));
+            if ranges.len() > entry.2 {
+                entry.0 = word;
+                entry.2 = ranges.len();
+            }
+            entry.1.extend(ranges);
         }
+        self.0 = by_stem
+            .into_values()
+            .map(|(word, mut ranges, _)| {
+                ranges.sort_by_key(|x| x.start);
+                (word, ranges)
+            })
+            .collect();
+        self.0.sort_by_key(|x| std::cmp::Reverse(x.1.len()));
+        before - self.0.len()
     }
 
-    fn color_green_field_2(&self) -> egui::Color32 {
-        if self.white_theme {
-            egui::Color32::from_rgba_unmultiplied(0, 195, 63, 201)
-        } else {
-            egui::Color32::from_rgb_additive(0, 128, 0)
-        }
+    /// Сортирует слова так, чтобы первыми шли самые частые в языке. Слова, которых нет в списке частот, идут после, по частоте в тексте.
+    fn sort_by_frequency(&mut self, frequencies: &BTreeMap<String, u64>) {
+        self.0.sort_by_key(|(word, ranges)| {
+            std::cmp::Reverse((frequencies.get(word).copied(), ranges.len()))
+        });
     }
+}
 
-    fn color_green_field_3(&self) -> egui::Color32 {
-        if self.white_theme {
-            egui::Color32::from_rgba_unmultiplied(47, 198, 0, 191)
-        } else {
-            egui::Color32::from_rgb_additive(128, 255, 128)
+/// Читает список частот: на каждой строке слово и, через пробел, его частота. Если частоты нет, то она считается по номеру строки.
+fn parse_frequency_list(text: &str) -> BTreeMap<String, u64> {
+    let lines: Vec<&str> = text.lines().filter(|x| !x.trim().is_empty()).collect();
+    let mut result = BTreeMap::new();
+    for (pos, line) in lines.iter().enumerate() {
+        let mut parts = line.split_whitespace();
+        if let Some(word) = parts.next() {
+            let frequency = parts
+                .next()
+                .and_then(|x| x.parse().ok())
+                .unwrap_or((lines.len() - pos) as u64);
+            result.entry(word.to_lowercase()).or_insert(frequency);
         }
     }
+    result
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug, Ord, PartialOrd, Eq, PartialEq)]
-pub enum WordType {
-    Known,
-    Trash,
-    Level(u8),
-    Learned,
+struct GetWordsResult {
+    text: String,
+    words_with_context: WordsWithContext,
+    words_count: usize,
+    unique_words_count: usize,
 }
 
-#[derive(Default, Serialize, Deserialize, Clone, Debug)]
-pub struct DayStatistics {
-    attempts: TypingStats,
-    new_unknown_words_count: u64,
-    word_count_by_level: BTreeMap<WordType, u64>,
-    working_time: f64,
+fn is_word_symbol(c: char) -> bool {
+    c.is_alphabetic() || c == '\'' || c == '-'
 }
 
-#[derive(Default, Serialize, Deserialize, Clone, Debug)]
-pub struct Statistics {
-    by_day: BTreeMap<Day, DayStatistics>,
+fn is_sentence_end(c: char) -> bool {
+    matches!(c, '.' | '!' | '?' | '\n')
 }
 
-mod gui {
-    use super::*;
-    use egui::*;
-
-    struct ClosableWindow<T: WindowTrait>(Option<T>);
-
-    impl<T: WindowTrait> Default for ClosableWindow<T> {
-        fn default() -> Self {
-            Self(None)
-        }
+/// Сдвигает границы диапазона байт так, чтобы они не попадали в середину символа, иначе срез строки упадёт
+fn snap_to_char_boundaries(text: &str, range: std::ops::Range<usize>) -> std::ops::Range<usize> {
+    let mut start = range.start.min(text.len());
+    let mut end = range.end.min(text.len()).max(start);
+    while !text.is_char_boundary(start) {
+        start -= 1;
     }
+    while !text.is_char_boundary(end) {
+        end += 1;
+    }
+    start..end
+}
 
-    trait WindowTrait {
-        fn create_window(&self) -> Window<'static>;
+/// Расширяет диапазон слова до границ предложения, но не больше чем на `max` символов в каждую сторону
+fn sentence_context(
+    text: &str,
+    range: std::ops::Range<usize>,
+    max: usize,
+) -> std::ops::Range<usize> {
+    let range = snap_to_char_boundaries(text, range);
+    let mut start = range.start;
+    for c in text[..range.start].chars().rev().take(max) {
+        if is_sentence_end(c) {
+            break;
+        }
+        start -= c.len_utf8();
     }
 
-    impl<T: WindowTrait> ClosableWindow<T> {
-        fn new(t: T) -> Self {
-            Self(Some(t))
+    let mut end = range.end;
+    for c in text[range.end..].chars().take(max) {
+        end += c.len_utf8();
+        if is_sentence_end(c) {
+            break;
         }
+    }
 
-        /// Возвращение true в f означает что самого себя надо закрыть. Возвращение true в ui означает что окно закрылось
-        fn ui(&mut self, ctx: &CtxRef, f: impl FnOnce(&mut T, &mut Ui) -> bool) -> bool {
-            if let Some(t) = &mut self.0 {
-                let mut opened = true;
-                let mut want_to_be_closed = false;
+    start..end
+}
 
-                t.create_window()
-                    .open(&mut opened)
-                    .show(ctx, |ui| want_to_be_closed = f(t, ui));
+/// Убирает лишние пробелы, чтобы фразы из нескольких слов хранились и сравнивались одинаково
+fn normalize_phrase(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
 
-                if !opened || want_to_be_closed {
-                    self.0 = None;
-                    return true;
-                }
-            }
-            false
-        }
-    }
+/// Темы через запятую
+fn parse_tags(text: &str) -> BTreeSet<String> {
+    text.split(',')
+        .map(normalize_phrase)
+        .filter(|x| !x.is_empty())
+        .collect()
+}
 
-    pub struct Program {
-        words: Words,
-        settings: Settings,
-        stats: Statistics,
+fn tags_to_string(tags: &BTreeSet<String>) -> String {
+    tags.iter().cloned().collect::<Vec<_>>().join(", ")
+}
 
-        /// Известные, мусорные, выученные, добавленные слова, необходимо для фильтрации после добавления слова
-        known_words: BTreeSet<String>,
-        learn_window: LearnWordsWindow,
-        load_text_window: ClosableWindow<LoadTextWindow>,
-        add_words_window: ClosableWindow<AddWordsWindow>,
-        add_custom_words_window: ClosableWindow<AddCustomWordsWindow>,
+/// Убирает диакритические знаки с букв, сохраняя регистр
+fn remove_accents(text: &str) -> String {
+    fn base(c: char) -> Option<&'static str> {
+        Some(match c {
+            'à' | 'á' | 'â' | 'ã' | 'ä' | 'å' | 'ā' | 'ă' | 'ą' => "a",
+            'ç' | 'ć' | 'č' => "c",
+            'ď' | 'đ' => "d",
+            'è' | 'é' | 'ê' | 'ë' | 'ē' | 'ė' | 'ę' | 'ě' => "e",
+            'ğ' => "g",
+            'ì' | 'í' | 'î' | 'ï' | 'ī' | 'į' => "i",
+            'ł' => "l",
+            'ñ' | 'ń' | 'ň' => "n",
+            'ò' | 'ó' | 'ô' | 'õ' | 'ö' | 'ø' | 'ō' | 'ő' => "o",
+            'ř' => "r",
+            'ś' | 'š' | 'ş' => "s",
+            'ß' => "ss",
+            'ť' | 'ţ' => "t",
+            'ù' | 'ú' | 'û' | 'ü' | 'ū' | 'ů' | 'ű' => "u",
+            'ý' | 'ÿ' => "y",
+            'ź' | 'ż' | 'ž' => "z",
+            'ё' => "е",
+            _ => return None,
+        })
+    }
 
-        full_stats_window: ClosableWindow<FullStatsWindow>,
-        percentage_graph_window: ClosableWindow<PercentageGraphWindow>,
-        github_activity_window: ClosableWindow<GithubActivityWindow>,
+    let mut result = String::with_capacity(text.len());
+    for c in text.chars() {
+        let lowercase = c.to_lowercase().next().unwrap_or(c);
+        match base(lowercase) {
+            Some(base) if c != lowercase => result += &base.to_uppercase(),
+            Some(base) => result += base,
+            None => result.push(c),
+        }
+    }
+    result
+}
 
-        import_window: ClosableWindow<ImportWindow>,
-        export_window: ClosableWindow<ExportWindow>,
-        settings_window: ClosableWindow<SettingsWindow>,
-        about_window: ClosableWindow<AboutWindow>,
-        search_words_window: ClosableWindow<SearchWordsWindow>,
-        edit_word_window: ClosableWindow<EditWordWindow>,
-        info_window: ClosableWindow<InfoWindow>,
-        synchronous_subtitles_window: ClosableWindow<SynchronousSubtitlesWindow>,
+/// Приводит ответ к виду, в котором он сравнивается с правильным ответом
+fn normalize_answer(text: &str, settings: &Settings) -> String {
+    let mut result = normalize_phrase(text);
+    if !settings.case_sensitive {
+        result = result.to_lowercase();
     }
+    if !settings.accent_sensitive {
+        result = remove_accents(&result);
+    }
+    result
+}
 
-    impl Program {
-        pub fn new(
-            words: Words,
-            settings: Settings,
-            stats: Statistics,
-            today: Day,
-            working_time: f64,
-            rng: &mut Rand,
-        ) -> Self {
-            let learn_window = LearnWordsWindow::new(&words, today, &settings.type_count, rng);
-            let known_words = words.calculate_known_words();
+/// Как текст разбивается на слова. По умолчанию апострофы и дефисы считаются частью слова.
+#[derive(Clone, Copy, Debug, Default)]
+struct TokenizeOptions {
+    /// Дефис разделяет слова, иначе `mother-in-law` — одно слово
+    hyphen_separates: bool,
+    /// Убирать апострофы в начале и в конце слова, как в `'quoted'`. Апострофы внутри слова, как в `don't`, остаются.
+    strip_apostrophes: bool,
+    /// Цифры тоже часть слова, как в `covid19` или `2nd`. Иначе они разделяют слова.
+    alphanumeric: bool,
+    /// Пропускать слова без единой буквы, например `100`
+    skip_numbers: bool,
+}
 
-            let mut result = Self {
-                words,
-                settings,
-                stats,
+impl TokenizeOptions {
+    fn is_word_symbol(&self, c: char) -> bool {
+        if c == '-' && self.hyphen_separates {
+            false
+        } else {
+            is_word_symbol(c) || (self.alphanumeric && c.is_alphanumeric())
+        }
+    }
+}
 
-                known_words,
-                learn_window,
-                load_text_window: Default::default(),
-                add_words_window: Default::default(),
-                add_custom_words_window: Default::default(),
+fn get_words(text: &str, case_sensitive: bool, options: TokenizeOptions) -> GetWordsResult {
+    let mut extractor = WordsExtractor::new(text.to_owned(), case_sensitive, options);
+    while !extractor.step(usize::MAX) {}
+    extractor.finish()
+}
 
-                full_stats_window: Default::default(),
-                percentage_graph_window: Default::default(),
-                github_activity_window: Default::default(),
+/// Тексты больше этого размера разбираются по частям на нескольких кадрах, с индикатором прогресса
+const LARGE_TEXT_SIZE: usize = 1024 * 1024;
 
-                import_window: Default::default(),
-                export_window: Default::default(),
-                settings_window: Default::default(),
-                about_window: Default::default(),
-                search_words_window: Default::default(),
-                edit_word_window: Default::default(),
-                info_window: Default::default(),
-                synchronous_subtitles_window: Default::default(),
-            };
+/// Сколько байт текста разбирается за один кадр, чтобы большой текст не замораживал интерфейс
+const EXTRACT_BYTES_PER_FRAME: usize = 128 * 1024;
 
-            result.open_activity(today, working_time);
+/// Разбирает текст на слова по частям, каждый вызов `step` обрабатывает только кусок текста
+struct WordsExtractor {
+    text: String,
+    case_sensitive: bool,
+    options: TokenizeOptions,
+    /// До какого байта текст уже разобран
+    position: usize,
+    words: BTreeMap<String, Vec<std::ops::Range<usize>>>,
+    words_count: usize,
+    /// Слово, которое ещё не закончилось, и байт его начала
+    current_word: Option<(String, usize)>,
+}
 
-            result
+impl WordsExtractor {
+    fn new(text: String, case_sensitive: bool, options: TokenizeOptions) -> Self {
+        Self {
+            text,
+            case_sensitive,
+            options,
+            position: 0,
+            words: BTreeMap::new(),
+            words_count: 0,
+            current_word: None,
         }
+    }
 
-        pub fn get_settings(&self) -> &Settings {
-            &self.settings
+    /// Разбирает следующие `max_bytes` байт текста, возвращает true, когда весь текст разобран
+    fn step(&mut self, max_bytes: usize) -> bool {
+        let text = std::mem::take(&mut self.text);
+        let mut end = self.position.saturating_add(max_bytes).min(text.len());
+        while !text.is_char_boundary(end) {
+            end += 1;
+        }
+        for (i, c) in text[self.position..end].char_indices() {
+            let i = self.position + i;
+            if self.options.is_word_symbol(c) {
+                let (word, _) = self.current_word.get_or_insert_with(|| (String::new(), i));
+                // Символ добавляется к слову на месте, без выделения памяти под каждый символ
+                if self.case_sensitive {
+                    word.push(c);
+                } else {
+                    word.extend(c.to_lowercase());
+                }
+            } else {
+                self.end_word(i);
+            }
         }
-
-        pub fn save_to_string(&mut self, today: Day, working_time: f64) -> String {
-            self.update_day_statistics(today, working_time);
-            ron::to_string(&(&self.words, &self.settings, &self.stats)).unwrap()
+        self.position = end;
+        let finished = end == text.len();
+        self.text = text;
+        if finished {
+            self.end_word(end);
         }
+        finished
+    }
 
-        pub fn save(&mut self, today: Day, working_time: f64) {
-            quad_storage::STORAGE.lock().unwrap().set(
-                "learn_words_data",
-                &self.save_to_string(today, working_time),
-            );
+    fn end_word(&mut self, mut end: usize) {
+        if let Some((mut word, mut start)) = self.current_word.take() {
+            if self.options.strip_apostrophes {
+                // Апостроф занимает один байт и в слове, и в тексте, поэтому границы сдвигаются на столько же
+                let leading = word.len() - word.trim_start_matches('\'').len();
+                let trailing = word.len() - word.trim_end_matches('\'').len();
+                if leading == word.len() {
+                    return;
+                }
+                word = word[leading..word.len() - trailing].to_string();
+                start += leading;
+                end -= trailing;
+            }
+            if self.options.skip_numbers && !word.chars().any(char::is_alphabetic) {
+                return;
+            }
+            self.words_count += 1;
+            self.words.entry(word).or_default().push(start..end);
         }
+    }
 
-        pub fn load() -> (Words, Settings, Statistics) {
-            quad_storage::STORAGE
-                .lock()
-                .unwrap()
-                .get("learn_words_data")
-                .map(|x| Self::load_from_string(&x).unwrap())
-                .unwrap_or_default()
+    /// Какая часть текста уже разобрана, от 0 до 1
+    fn progress(&self) -> f32 {
+        if self.text.is_empty() {
+            1.
+        } else {
+            self.position as f32 / self.text.len() as f32
+        }
+    }
+
+    fn finish(self) -> GetWordsResult {
+        let mut words: Vec<_> = self.words.into_iter().collect();
+
+        words.sort_by_key(|x| std::cmp::Reverse(x.1.len()));
+
+        let unique_words_count = words.len();
+
+        GetWordsResult {
+            text: self.text,
+            words_with_context: WordsWithContext(words),
+            words_count: self.words_count,
+            unique_words_count,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Settings {
+    type_count: Vec<LearnType>,
+    /// Отдельные расписания для слов с темой, например языком: `zh` → больше повторений. Слово берёт расписание первой своей темы, для которой оно задано, иначе `type_count`.
+    #[serde(default)]
+    tag_schedules: BTreeMap<String, Vec<LearnType>>,
+    time_to_pause: f64,
+    /// Во сколько часов по местному времени начинается новый день
+    #[serde(default)]
+    day_start_hour: u8,
+    /// Смещение часового пояса в часах, если None, то берётся из системы
+    #[serde(default)]
+    timezone_offset: Option<f64>,
+    /// Через сколько секунд после последнего сохранения данные сохраняются сами, 0 — никогда
+    #[serde(default = "default_autosave_interval")]
+    autosave_interval: f64,
+    /// Насколько должна сдвинуться мышь, чтобы это считалось действием и сбрасывало паузу
+    #[serde(default = "default_pause_mouse_threshold")]
+    pause_mouse_threshold: f32,
+    use_keyboard_layout: bool,
+    keyboard_layout: KeyboardLayout,
+    /// Масштаб интерфейса, раньше назывался dpi
+    #[serde(alias = "dpi")]
+    ui_scale: f32,
+    /// Светлая ли тема сейчас. Вычисляется из `theme` на каждом кадре, а читается только для перехода со старых версий, где была только эта настройка
+    #[serde(default, skip_serializing)]
+    white_theme: bool,
+    #[serde(default)]
+    theme: Theme,
+    /// Через сколько неправильных ответов подряд слово сбрасывается на нулевой уровень, 0 — никогда
+    #[serde(default)]
+    max_wrong_before_reset: u8,
+    /// Чаще показывать слова, в которых много ошибок
+    #[serde(default)]
+    prioritize_hard_words: bool,
+    /// Что повторять сначала: старые слова, новые или вперемешку
+    #[serde(default)]
+    order: SessionOrder,
+    /// Сразу начинать изучение всех слов на сегодня, не выбирая их количество
+    #[serde(default)]
+    skip_choose: bool,
+    /// Показывать первые буквы угадываемого слова, по одной за каждый прошлый неправильный ответ
+    #[serde(default)]
+    progressive_hints: bool,
+    /// Через сколько секунд ответ на карточку проверяется сам, None — без ограничения
+    #[serde(default)]
+    card_time_limit: Option<f64>,
+    /// Иногда показывать перевод и спрашивать слово, чтобы слово запоминалось в обе стороны
+    #[serde(default)]
+    mixed_directions: bool,
+    /// Различать заглавные и строчные буквы при добавлении и проверке слов
+    #[serde(default)]
+    case_sensitive: bool,
+    /// Различать буквы с диакритическими знаками при проверке слов
+    #[serde(default = "default_true")]
+    accent_sensitive: bool,
+    /// Сколько опечаток допускается в ответе, чтобы он был засчитан
+    #[serde(default)]
+    accept_typo_distance: usize,
+    /// Максимальное количество символов контекста с каждой стороны слова
+    #[serde(default = "default_context_max_length")]
+    context_max_length: usize,
+    /// Сколько вхождений слова в текст показывать сразу, остальные показываются по кнопке
+    #[serde(default = "default_context_occurrences")]
+    context_occurrences: usize,
+    /// Слова, которые пропускаются при добавлении слов из текста, вдобавок к `BUILTIN_STOPWORDS`
+    #[serde(default)]
+    stopwords: BTreeSet<String>,
+    /// Сколько новых переводов можно добавить за день, 0 — без ограничения
+    #[serde(default)]
+    daily_new_limit: u64,
+    /// Сколько старых слов можно повторить за день, 0 — без ограничения. Остальные слова переносятся на следующий день
+    #[serde(default)]
+    daily_review_limit: u64,
+    /// Команда для произношения слов: `{text}` заменяется на слово, `{lang}` на его язык. Пустая строка — произношение выключено
+    #[serde(default)]
+    speech_command: String,
+    /// Язык для произношения слов каждой раскладки по порядку, например `en-US`
+    #[serde(default)]
+    speech_langs: Vec<String>,
+    /// Старый формат из языков ровно двух раскладок, при загрузке переводится в `speech_langs`
+    #[serde(default, skip_serializing)]
+    speech_lang1: String,
+    #[serde(default, skip_serializing)]
+    speech_lang2: String,
+    /// Цвета календаря активности
+    #[serde(default)]
+    activity_palette: ActivityPalette,
+    /// Растягивать маленькие значения в календаре активности, иначе цвет зависит от значения линейно
+    #[serde(default = "default_true")]
+    activity_gamma: bool,
+    /// Размер окна при последнем запуске в логических пикселях, None — размер по умолчанию
+    #[serde(default)]
+    window_size: Option<(f32, f32)>,
+}
+
+/// Порядок новых и старых слов в одной сессии. Новые — слова на нулевом уровне.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Default)]
+enum SessionOrder {
+    ReviewsFirst,
+    NewFirst,
+    #[default]
+    Mixed,
+}
+
+impl SessionOrder {
+    fn name(&self) -> &'static str {
+        match self {
+            SessionOrder::ReviewsFirst => "Reviews first",
+            SessionOrder::NewFirst => "New first",
+            SessionOrder::Mixed => "Mixed",
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Default)]
+enum ActivityPalette {
+    #[default]
+    Green,
+    Blue,
+    /// Различима при дальтонизме
+    Viridis,
+}
+
+impl ActivityPalette {
+    fn name(&self) -> &'static str {
+        match self {
+            ActivityPalette::Green => "Green",
+            ActivityPalette::Blue => "Blue",
+            ActivityPalette::Viridis => "Viridis",
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Default)]
+enum Theme {
+    #[default]
+    Dark,
+    Light,
+    /// Как в системе
+    System,
+}
+
+impl Theme {
+    fn name(&self) -> &'static str {
+        match self {
+            Theme::Dark => "Dark",
+            Theme::Light => "Light",
+            Theme::System => "System",
+        }
+    }
+
+    fn is_light(&self) -> bool {
+        match self {
+            Theme::Dark => false,
+            Theme::Light => true,
+            Theme::System => system_prefers_light(),
+        }
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+fn system_prefers_light() -> bool {
+    web_sys::window()
+        .and_then(|window| window.match_media("(prefers-color-scheme: light)").ok())
+        .flatten()
+        .map(|query| query.matches())
+        .unwrap_or(false)
+}
+
+/// Нативная версия не умеет узнавать тему системы, поэтому используется тёмная тема
+#[cfg(not(target_arch = "wasm32"))]
+fn system_prefers_light() -> bool {
+    false
+}
+
+/// Больше этого размера файлы по ссылке не скачиваются
+const MAX_DOWNLOAD_SIZE: usize = 10 * 1024 * 1024;
+
+/// Результат скачивания, который появляется, когда загрузка завершится
+type Download<T = String> = std::sync::Arc<std::sync::Mutex<Option<Result<T, String>>>>;
+
+/// Асинхронно скачивает текстовый файл, прерывая загрузку, если он слишком большой
+fn fetch_text(url: &str) -> Download {
+    fetch(url, |body| String::from_utf8_lossy(&body).into_owned())
+}
+
+/// Асинхронно скачивает файл целиком, например картинку
+fn fetch_bytes(url: &str) -> Download<Vec<u8>> {
+    fetch(url, |body| body)
+}
+
+fn fetch<T: Send + 'static>(url: &str, convert: fn(Vec<u8>) -> T) -> Download<T> {
+    use ehttp::streaming::Part;
+    use std::ops::ControlFlow;
+
+    let download = Download::default();
+    let result = download.clone();
+    let body = std::sync::Mutex::new(Vec::new());
+    let too_big = || {
+        Err(format!(
+            "File is larger than {} MB",
+            MAX_DOWNLOAD_SIZE / 1024 / 1024
+        ))
+    };
+    ehttp::streaming::fetch(ehttp::Request::get(url), move |part| {
+        let finish = |value: Result<T, String>| {
+            *result.lock().unwrap() = Some(value);
+            ControlFlow::Break(())
+        };
+        match part {
+            Err(error) => finish(Err(fetch_error_message(error))),
+            Ok(Part::Response(response)) => {
+                let length = response
+                    .headers
+                    .get("content-length")
+                    .and_then(|x| x.parse::<usize>().ok());
+                if !response.ok {
+                    finish(Err(format!(
+                        "Server responded with {} {}",
+                        response.status, response.status_text
+                    )))
+                } else if length.map(|x| x > MAX_DOWNLOAD_SIZE).unwrap_or(false) {
+                    finish(too_big())
+                } else {
+                    ControlFlow::Continue(())
+                }
+            }
+            Ok(Part::Chunk(chunk)) => {
+                let mut body = body.lock().unwrap();
+                if chunk.is_empty() {
+                    finish(Ok(convert(std::mem::take(&mut *body))))
+                } else if body.len() + chunk.len() > MAX_DOWNLOAD_SIZE {
+                    finish(too_big())
+                } else {
+                    body.extend(chunk);
+                    ControlFlow::Continue(())
+                }
+            }
+        }
+    });
+    download
+}
+
+/// Браузер не сообщает причину ошибки, а чаще всего это запрет CORS
+#[cfg(target_arch = "wasm32")]
+fn fetch_error_message(error: String) -> String {
+    format!(
+        "{}. Probably this site doesn't allow downloads from other sites (CORS). Download the file and paste its contents instead.",
+        error
+    )
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn fetch_error_message(error: String) -> String {
+    error
+}
+
+fn default_context_max_length() -> usize {
+    200
+}
+
+fn default_context_occurrences() -> usize {
+    20
+}
+
+/// Самые частые служебные слова английского и русского через пробел, их не нужно учить
+const BUILTIN_STOPWORDS: &str = "\
+    a about after all an and any are as at be been but by can could did do does for \
+    from had has have he her him his i if in into is it its me my no not of on or \
+    our she so than that the their them then there they this to up us was we were \
+    what when which who will with would you your а без бы в во вот вы да для до его \
+    ее её если же за и из или им их к как ли мы на не нет но о об он она они оно от \
+    по при с со так там то тоже только ты у уже что это я";
+
+fn is_stopword(word: &str, settings: &Settings) -> bool {
+    let word = word.to_lowercase();
+    BUILTIN_STOPWORDS.split_whitespace().any(|x| x == word) || settings.stopwords.contains(&word)
+}
+
+/// Слова через запятую или пробел, в нижнем регистре
+fn parse_stopwords(text: &str) -> BTreeSet<String> {
+    text.split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|x| !x.is_empty())
+        .map(|x| x.to_lowercase())
+        .collect()
+}
+
+/// Размер окна при первом запуске, помещается на большинство экранов
+const DEFAULT_WINDOW_SIZE: (f32, f32) = (1280., 800.);
+
+impl Settings {
+    /// Расписание повторений для этого перевода
+    fn schedule_for(&self, status: &WordStatus) -> &[LearnType] {
+        status
+            .tags()
+            .into_iter()
+            .flatten()
+            .find_map(|tag| self.tag_schedules.get(tag))
+            .filter(|x| !x.is_empty())
+            .unwrap_or(&self.type_count)
+    }
+
+    fn window_size(&self) -> egui::Vec2 {
+        let (width, height) = self.window_size.unwrap_or(DEFAULT_WINDOW_SIZE);
+        egui::vec2(width, height)
+    }
+}
+
+fn default_autosave_interval() -> f64 {
+    60.
+}
+
+fn default_pause_mouse_threshold() -> f32 {
+    0.01
+}
+
+fn default_true() -> bool {
+    true
+}
+
+/// Раскладки клавиатуры. Все раскладки набираются на одних и тех же клавишах, клавиша обозначается символом первой раскладки, а если там заглушка — символом первой раскладки, где он есть.
+#[derive(Default, Serialize, Deserialize, Clone, Debug)]
+struct KeyboardLayout {
+    /// Название раскладки и её символы: символ клавиши → символ этой раскладки
+    #[serde(default)]
+    profiles: Vec<(String, BTreeMap<char, char>)>,
+
+    /// Старый формат из ровно двух раскладок, при загрузке переводится в `profiles`
+    #[serde(default, skip_serializing)]
+    lang1: BTreeMap<char, char>,
+    #[serde(default, skip_serializing)]
+    lang2: BTreeMap<char, char>,
+}
+
+impl KeyboardLayout {
+    /// Каждая раскладка задаётся названием и символами в одном и том же порядке клавиш
+    fn new(profiles: &[(String, String)]) -> Result<KeyboardLayout, String> {
+        if profiles.len() < 2 {
+            return Err("There must be at least two layouts.".to_string());
+        }
+        let symbols: Vec<Vec<char>> = profiles
+            .iter()
+            .map(|(_, text)| text.chars().filter(|x| *x != '\n').collect())
+            .collect();
+        for (i, profile) in symbols.iter().enumerate() {
+            if profile.iter().all(|c| c.is_whitespace()) {
+                return Err(format!(
+                    "Layout '{}' is empty or has only spaces, it must have at least one symbol.",
+                    profiles[i].0
+                ));
+            }
+        }
+        for (i, profile) in symbols.iter().enumerate().skip(1) {
+            if profile.len() != symbols[0].len() {
+                return Err(format!(
+                    "Lengths of symbols are not equal: {} ≠ {} in '{}'",
+                    symbols[0].len(),
+                    profile.len(),
+                    profiles[i].0,
+                ));
+            }
+        }
+
+        for (i, a) in symbols.iter().enumerate() {
+            for (j, b) in symbols.iter().enumerate().skip(i + 1) {
+                if let Some(c) = a.iter().filter(|c| **c != ' ').find(|c| b.contains(c)) {
+                    return Err(format!(
+                        "Symbol '{}' is in both '{}' and '{}' layouts.",
+                        c, profiles[i].0, profiles[j].0
+                    ));
+                }
+            }
+        }
+
+        // Пробел — заглушка для символа, которого нет в раскладке. Клавиша, на которой во всех раскладках заглушки, не попадает в раскладку и не меняется при вводе.
+        let keys: Vec<Option<char>> = (0..symbols[0].len())
+            .map(|i| symbols.iter().map(|x| x[i]).find(|c| *c != ' '))
+            .collect();
+        let profiles = profiles
+            .iter()
+            .zip(symbols.iter())
+            .map(|((name, _), profile)| {
+                let map = keys
+                    .iter()
+                    .zip(profile.iter().copied())
+                    .filter_map(|(key, c)| match key {
+                        Some(key) if c != ' ' => Some((*key, c)),
+                        _ => None,
+                    })
+                    .collect();
+                (name.clone(), map)
+            })
+            .collect();
+        Ok(Self {
+            profiles,
+            ..Default::default()
+        })
+    }
+
+    /// Переводит старый формат из двух раскладок в список раскладок
+    fn migrate_two_langs(&mut self) {
+        if !self.profiles.is_empty() || self.lang1.is_empty() {
+            return;
+        }
+        let lang1 = std::mem::take(&mut self.lang1);
+        self.lang2.clear();
+        self.profiles = vec![
+            (
+                "First language".to_string(),
+                lang1.keys().map(|x| (*x, *x)).collect(),
+            ),
+            ("Second language".to_string(), lang1),
+        ];
+    }
+
+    /// Раскладка, в которой набирается первый символ текста
+    fn profile_of(&self, text: &str) -> Option<usize> {
+        let c = text.chars().next()?;
+        self.profiles
+            .iter()
+            .position(|(_, map)| c != ' ' && map.values().any(|x| *x == c))
+    }
+
+    /// Буквы из слов и их переводов, которых нет ни в одной раскладке. Такие буквы не меняются при смене раскладки.
+    fn unmapped_letters(&self, words: &Words) -> BTreeSet<char> {
+        let mapped: BTreeSet<char> = self
+            .profiles
+            .iter()
+            .flat_map(|(_, map)| map.values().copied())
+            .collect();
+        words
+            .iter()
+            .filter(|(_, statuses)| statuses.iter().any(|x| x.translation().is_some()))
+            .flat_map(|(word, statuses)| {
+                std::iter::once(word).chain(statuses.iter().filter_map(|x| x.translation()))
+            })
+            .flat_map(|text| text.chars())
+            .filter(|c| c.is_alphabetic())
+            .flat_map(|c| c.to_lowercase())
+            .filter(|c| !mapped.contains(c))
+            .collect()
+    }
+
+    /// Символы раскладки для клавиш всех раскладок в порядке `char` клавиш, вместо отсутствующих в этой раскладке символов пробелы
+    fn profile_symbols(&self, profile: usize) -> String {
+        let map = &self.profiles[profile].1;
+        let keys: BTreeSet<char> = self
+            .profiles
+            .iter()
+            .flat_map(|(_, map)| map.keys().copied())
+            .collect();
+        keys.iter()
+            .map(|key| map.get(key).copied().unwrap_or(' '))
+            .collect()
+    }
+
+    /// Переводит текст из одной раскладки в другую. Пробел не меняется и не появляется вместо других символов, даже в раскладках, сохранённых до того, как пробелы стали заглушками.
+    fn change(&self, from: usize, to: usize, to_change: &mut String) {
+        if to_change.is_empty() {
+            return;
+        }
+        let from = &self.profiles[from].1;
+        let to = &self.profiles[to].1;
+        let lang: BTreeMap<char, char> = from
+            .iter()
+            .filter_map(|(key, c)| Some((*c, *to.get(key)?)))
+            .collect();
+        *to_change = to_change
+            .chars()
+            .map(|x| {
+                if let Some(c) = lang.get(&x).filter(|c| x != ' ' && **c != ' ') {
+                    *c
+                } else {
+                    x
+                }
+            })
+            .collect();
+    }
+}
+
+/// Произносит текст вслух
+pub trait SpeechSynth {
+    fn speak(&self, text: &str, lang: &str);
+}
+
+/// Синтез речи браузера
+#[cfg(target_arch = "wasm32")]
+struct WebSpeechSynth(web_sys::SpeechSynthesis);
+
+#[cfg(target_arch = "wasm32")]
+impl SpeechSynth for WebSpeechSynth {
+    fn speak(&self, text: &str, lang: &str) {
+        if let Ok(utterance) = web_sys::SpeechSynthesisUtterance::new_with_text(text) {
+            if !lang.is_empty() {
+                utterance.set_lang(lang);
+            }
+            self.0.speak(&utterance);
+        }
+    }
+}
+
+/// Запускает внешнюю программу, например `espeak -v {lang} {text}`
+#[cfg(not(target_arch = "wasm32"))]
+struct CommandSpeechSynth(String);
+
+#[cfg(not(target_arch = "wasm32"))]
+impl SpeechSynth for CommandSpeechSynth {
+    fn speak(&self, text: &str, lang: &str) {
+        let mut args = self
+            .0
+            .split_whitespace()
+            .map(|x| x.replace("{text}", text).replace("{lang}", lang));
+        if let Some(program) = args.next() {
+            match std::process::Command::new(&program).args(args).spawn() {
+                Ok(mut child) => {
+                    std::thread::spawn(move || child.wait());
+                }
+                Err(error) => eprintln!("Failed to run `{}`: {}", program, error),
+            }
+        }
+    }
+}
+
+/// Синтез речи, если он доступен
+fn speech_synth(settings: &Settings) -> Option<Box<dyn SpeechSynth>> {
+    #[cfg(target_arch = "wasm32")]
+    {
+        let _ = settings;
+        let synth = web_sys::window()?.speech_synthesis().ok()?;
+        Some(Box::new(WebSpeechSynth(synth)))
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        if settings.speech_command.trim().is_empty() {
+            None
+        } else {
+            Some(Box::new(CommandSpeechSynth(
+                settings.speech_command.clone(),
+            )))
+        }
+    }
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Settings {
+            type_count: vec![
+                LearnType::show(0, 2),
+                LearnType::guess(0, 3),
+                LearnType::guess(2, 3),
+                LearnType::guess(7, 2),
+                LearnType::guess(20, 2),
+            ],
+            time_to_pause: 15.,
+            day_start_hour: 0,
+            timezone_offset: None,
+            autosave_interval: default_autosave_interval(),
+            pause_mouse_threshold: default_pause_mouse_threshold(),
+            use_keyboard_layout: false,
+            keyboard_layout: Default::default(),
+            ui_scale: 1.0,
+            white_theme: false,
+            theme: Theme::Dark,
+            max_wrong_before_reset: 0,
+            prioritize_hard_words: false,
+            order: SessionOrder::Mixed,
+            progressive_hints: false,
+            card_time_limit: None,
+            mixed_directions: false,
+            skip_choose: false,
+            case_sensitive: false,
+            accent_sensitive: true,
+            accept_typo_distance: 0,
+            context_max_length: default_context_max_length(),
+            context_occurrences: default_context_occurrences(),
+            stopwords: BTreeSet::new(),
+            tag_schedules: BTreeMap::new(),
+            daily_new_limit: 0,
+            daily_review_limit: 0,
+            speech_command: String::new(),
+            speech_langs: Vec::new(),
+            speech_lang1: String::new(),
+            speech_lang2: String::new(),
+            activity_palette: ActivityPalette::Green,
+            activity_gamma: true,
+            window_size: None,
+        }
+    }
+}
+
+impl Settings {
+    /// Язык слова определяется по раскладке, в которой набирается его первая буква. Если язык этой раскладки не задан, он угадывается по письменности
+    fn speech_lang(&self, text: &str) -> &str {
+        let first_letter: String = text.chars().flat_map(char::to_lowercase).take(1).collect();
+        let profile = self.keyboard_layout.profile_of(&first_letter).unwrap_or(0);
+        match self.speech_langs.get(profile) {
+            Some(lang) if !lang.is_empty() => lang,
+            _ => detect_script(text).default_speech_lang(),
+        }
+    }
+
+    fn new_words_limit_reached(&self, day_stats: &DayStatistics) -> bool {
+        self.daily_new_limit != 0 && day_stats.new_unknown_words_count >= self.daily_new_limit
+    }
+
+    /// На сколько часов сдвигается время UTC, чтобы день начинался в `day_start_hour` по местному времени
+    fn day_hour_offset(&self) -> f64 {
+        self.timezone_offset.unwrap_or_else(timezone_offset_hours) - self.day_start_hour as f64
+    }
+
+    fn review_limit_left(&self, day_stats: &DayStatistics) -> usize {
+        if self.daily_review_limit == 0 {
+            usize::MAX
+        } else {
+            self.daily_review_limit
+                .saturating_sub(day_stats.reviewed_words_count) as usize
+        }
+    }
+
+    fn color_github_zero(&self) -> egui::Color32 {
+        if self.white_theme {
+            egui::Color32::from_gray(240)
+        } else {
+            egui::Color32::from_gray(24)
+        }
+    }
+
+    fn color_github_month(&self) -> egui::Color32 {
+        if self.white_theme {
+            egui::Color32::from_rgba_unmultiplied(76, 76, 76, 255)
+        } else {
+            egui::Color32::WHITE
+        }
+    }
+
+    fn color_github_year(&self) -> egui::Color32 {
+        if self.white_theme {
+            egui::Color32::from_rgba_unmultiplied(226, 31, 31, 255)
+        } else {
+            egui::Color32::RED
+        }
+    }
+
+    fn color_delete(&self) -> egui::Color32 {
+        if self.white_theme {
+            egui::Color32::from_rgba_unmultiplied(213, 0, 0, 255)
+        } else {
+            egui::Color32::RED
+        }
+    }
+
+    fn color_add(&self) -> egui::Color32 {
+        if self.white_theme {
+            egui::Color32::from_rgba_unmultiplied(0, 171, 0, 255)
+        } else {
+            egui::Color32::RED
+        }
+    }
+
+    fn color_error(&self) -> egui::Color32 {
+        if self.white_theme {
+            egui::Color32::from_rgba_unmultiplied(255, 0, 0, 255)
+        } else {
+            egui::Color32::RED
+        }
+    }
+
+    fn color_due_today(&self) -> egui::Color32 {
+        if self.white_theme {
+            egui::Color32::from_rgba_unmultiplied(200, 150, 0, 255)
+        } else {
+            egui::Color32::YELLOW
+        }
+    }
+
+    fn color_learned(&self) -> egui::Color32 {
+        if self.white_theme {
+            egui::Color32::from_rgba_unmultiplied(0, 171, 0, 255)
+        } else {
+            egui::Color32::GREEN
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Ord, PartialOrd, Eq, PartialEq)]
+pub enum WordType {
+    Known,
+    Trash,
+    Level(u8),
+    Learned,
+    Suspended,
+}
+
+#[derive(Default, Serialize, Deserialize, Clone, Debug)]
+pub struct DayStatistics {
+    attempts: TypingStats,
+    new_unknown_words_count: u64,
+    word_count_by_level: BTreeMap<WordType, u64>,
+    /// Сколько изучаемых слов молодые и сколько зрелые на конец дня
+    #[serde(default)]
+    word_count_by_maturity: BTreeMap<Maturity, u64>,
+    working_time: f64,
+    /// Сколько старых слов было полностью повторено за день
+    #[serde(default)]
+    reviewed_words_count: u64,
+    /// Слова, которые перешли на следующий уровень за день
+    #[serde(default)]
+    words_leveled: Vec<String>,
+    /// Сколько переводов стали полностью выученными за день
+    #[serde(default)]
+    words_learned_today: u64,
+}
+
+#[derive(Default, Serialize, Deserialize, Clone, Debug)]
+pub struct Statistics {
+    by_day: BTreeMap<Day, DayStatistics>,
+}
+
+/// Текущая версия формата сохранения. Увеличивается, когда старые данные нужно преобразовывать при загрузке.
+const SAVE_VERSION: u32 = 4;
+
+/// Все сохраняемые данные вместе с версией формата. До версии 1 сохранялся просто кортеж без версии.
+#[derive(Deserialize)]
+struct SaveData {
+    version: u32,
+    words: Words,
+    settings: Settings,
+    stats: Statistics,
+}
+
+/// То же, что `SaveData`, но для сохранения без копирования данных
+#[derive(Serialize)]
+struct SaveDataRef<'a> {
+    version: u32,
+    words: &'a Words,
+    settings: &'a Settings,
+    stats: &'a Statistics,
+}
+
+impl SaveData {
+    fn from_str(s: &str) -> Result<SaveData, ron::Error> {
+        let versioned = s
+            .trim_start()
+            .trim_start_matches('(')
+            .trim_start()
+            .starts_with("version");
+        if versioned {
+            ron::from_str::<SaveData>(s)
+        } else {
+            let (words, settings, stats) = ron::from_str::<(Words, Settings, Statistics)>(s)?;
+            Ok(SaveData {
+                version: 0,
+                words,
+                settings,
+                stats,
+            })
+        }
+    }
+}
+
+/// Обновляет данные старых версий до текущей. Новые поля заполняются значениями по умолчанию ещё при чтении через `#[serde(default)]`, здесь переводятся только поля, формат которых изменился. Сами данные пользователя не исправляются.
+fn migrate(mut data: SaveData) -> SaveData {
+    while data.version < SAVE_VERSION {
+        match data.version {
+            0 => {
+                // Версия 0 — это кортеж без версии, его новые поля уже заполнены при чтении
+            }
+            1 => {
+                // Раньше было ровно две раскладки клавиатуры
+                data.settings.keyboard_layout.migrate_two_langs();
+            }
+            2 => {
+                // Раньше была только светлая или тёмная тема
+                if data.settings.white_theme {
+                    data.settings.theme = Theme::Light;
+                }
+            }
+            3 => {
+                // Раньше язык произношения задавался только для двух раскладок
+                let settings = &mut data.settings;
+                if settings.speech_langs.is_empty()
+                    && !(settings.speech_lang1.is_empty() && settings.speech_lang2.is_empty())
+                {
+                    settings.speech_langs = vec![
+                        std::mem::take(&mut settings.speech_lang1),
+                        std::mem::take(&mut settings.speech_lang2),
+                    ];
+                }
+            }
+            _ => unreachable!(),
+        }
+        data.version += 1;
+    }
+    data
+}
+
+impl Statistics {
+    /// Начинает учёт нового дня. Слова, отложенные на этот день из-за дневного лимита, засчитываются в новые слова этого дня, чтобы вместе с ними лимит не превышался. Если день уже начат, то ничего не меняется.
+    fn start_day(&mut self, today: Day, words: &Words) {
+        if self.by_day.contains_key(&today) {
+            return;
+        }
+        if let Some(deferred) = words.new_pairs_by_day(today).get(&today) {
+            self.by_day
+                .entry(today)
+                .or_default()
+                .new_unknown_words_count = *deferred;
+        }
+    }
+
+    /// Объединяет статистику с другого устройства, складывая попытки и время за каждый день
+    fn merge(&mut self, other: Statistics) {
+        for (day, other) in other.by_day {
+            let day_stats = self.by_day.entry(day).or_default();
+            day_stats.attempts.right += other.attempts.right;
+            day_stats.attempts.wrong += other.attempts.wrong;
+            day_stats.new_unknown_words_count += other.new_unknown_words_count;
+            day_stats.reviewed_words_count += other.reviewed_words_count;
+            day_stats.working_time += other.working_time;
+            day_stats.words_leveled.extend(other.words_leveled);
+            day_stats.words_learned_today += other.words_learned_today;
+            // Количество слов по уровням нельзя складывать, это снимок всех слов на конец дня
+            if day_stats.word_count_by_level.is_empty() {
+                day_stats.word_count_by_level = other.word_count_by_level;
+                day_stats.word_count_by_maturity = other.word_count_by_maturity;
+            }
+        }
+    }
+}
+
+/// Номер дня уже считается в местном времени со сдвигом начала дня, поэтому день с номером N — это просто N-й день от 1970-01-01
+fn date_from_day(day: Day) -> chrono::Date<chrono::Utc> {
+    use chrono::TimeZone;
+    chrono::Utc
+        .timestamp(day.0 as i64 * 24 * 60 * 60 + 3600, 0)
+        .date()
+}
+
+/// Текущая и самая длинная серия дней подряд, в которые была хотя бы одна попытка. Последний день в статистике считается сегодняшним: если в нём ещё не было попыток, текущая серия от этого не прерывается.
+fn compute_streaks(stats: &Statistics) -> (u32, u32) {
+    // Номера дней идут подряд по календарю, см. `date_from_day`
+    let is_next_day = |previous: Day, day: Day| previous.0 + 1 == day.0;
+    let mut longest = 0;
+    let mut run = 0;
+    let mut previous: Option<Day> = None;
+    for (day, day_stats) in &stats.by_day {
+        if day_stats.attempts.right + day_stats.attempts.wrong == 0 {
+            continue;
+        }
+        run = match previous {
+            Some(previous) if is_next_day(previous, *day) => run + 1,
+            _ => 1,
+        };
+        longest = longest.max(run);
+        previous = Some(*day);
+    }
+    let current = match (previous, stats.by_day.keys().next_back()) {
+        (Some(previous), Some(last)) if previous == *last || is_next_day(previous, *last) => run,
+        _ => 0,
+    };
+    (current, longest)
+}
+
+/// Статистика по дням в формате CSV, по одной колонке на каждый тип слов
+fn stats_to_csv(stats: &Statistics) -> String {
+    let available_types: BTreeSet<WordType> = stats
+        .by_day
+        .values()
+        .flat_map(|x| x.word_count_by_level.keys().cloned())
+        .collect();
+
+    let mut result = String::from("day,date,right,wrong,new_words,working_time");
+    for i in &available_types {
+        use WordType::*;
+        match i {
+            Known => result.push_str(",known"),
+            Trash => result.push_str(",trash"),
+            Level(l) => result.push_str(&format!(",level_{}", l)),
+            Learned => result.push_str(",learned"),
+            Suspended => result.push_str(",suspended"),
+        }
+    }
+    result.push('\n');
+
+    for (day, day_stats) in &stats.by_day {
+        result.push_str(&format!(
+            "{},{},{},{},{},{}",
+            day.0,
+            date_from_day(*day).format("%Y-%m-%d"),
+            day_stats.attempts.right,
+            day_stats.attempts.wrong,
+            day_stats.new_unknown_words_count,
+            day_stats.working_time,
+        ));
+        for i in &available_types {
+            result.push_str(&format!(
+                ",{}",
+                day_stats.word_count_by_level.get(i).copied().unwrap_or(0)
+            ));
+        }
+        result.push('\n');
+    }
+    result
+}
+
+mod gui {
+    use super::*;
+    use egui::*;
+
+    struct ClosableWindow<T: WindowTrait>(Option<T>);
+
+    impl<T: WindowTrait> Default for ClosableWindow<T> {
+        fn default() -> Self {
+            Self(None)
+        }
+    }
+
+    trait WindowTrait {
+        fn create_window(&self) -> Window<'static>;
+    }
+
+    impl<T: WindowTrait> ClosableWindow<T> {
+        fn new(t: T) -> Self {
+            Self(Some(t))
+        }
+
+        /// Возвращение true в f означает что самого себя надо закрыть. Возвращение true в ui означает что окно закрылось
+        fn ui(&mut self, ctx: &CtxRef, f: impl FnOnce(&mut T, &mut Ui) -> bool) -> bool {
+            if let Some(t) = &mut self.0 {
+                let mut opened = true;
+                let mut want_to_be_closed = false;
+
+                let response = t
+                    .create_window()
+                    .open(&mut opened)
+                    .show(ctx, |ui| want_to_be_closed = f(t, ui));
+
+                if let Some(response) = response {
+                    let response = response.response;
+                    let raised = response.hovered() && ctx.input().pointer.any_pressed();
+                    let escape = ctx.input().key_pressed(Key::Escape);
+                    let mut memory = ctx.memory();
+                    let order = memory.data_temp.get_mut_or_default::<WindowsOrder>();
+                    let is_top = order.shown(response.layer_id, raised);
+                    if escape && is_top {
+                        want_to_be_closed = true;
+                    }
+                }
+
+                if !opened || want_to_be_closed {
+                    self.0 = None;
+                    return true;
+                }
+            }
+            false
+        }
+    }
+
+    /// Порядок закрываемых окон, нужен чтобы Esc закрывал только верхнее окно
+    #[derive(Clone, Default)]
+    struct WindowsOrder {
+        /// От нижнего к верхнему
+        order: Vec<LayerId>,
+        shown_last_frame: Vec<LayerId>,
+        shown: Vec<LayerId>,
+    }
+
+    impl WindowsOrder {
+        fn begin_frame(ctx: &CtxRef) {
+            let mut memory = ctx.memory();
+            let this = memory.data_temp.get_mut_or_default::<WindowsOrder>();
+            this.shown_last_frame = std::mem::take(&mut this.shown);
+            let shown_last_frame = &this.shown_last_frame;
+            this.order.retain(|x| shown_last_frame.contains(x));
+        }
+
+        /// Отмечает что окно показано на этом кадре, возвращает true если оно верхнее
+        fn shown(&mut self, layer: LayerId, raised: bool) -> bool {
+            self.shown.push(layer);
+            let position = self.order.iter().position(|x| *x == layer);
+            if raised || position.is_none() {
+                if let Some(position) = position {
+                    self.order.remove(position);
+                }
+                self.order.push(layer);
+            }
+            self.order.last() == Some(&layer)
+        }
+    }
+
+    pub struct Program {
+        words: Words,
+        settings: Settings,
+        stats: Statistics,
+
+        /// Известные, мусорные, выученные, добавленные слова, необходимо для фильтрации после добавления слова
+        known_words: BTreeSet<String>,
+        learn_window: LearnWordsWindow,
+        load_text_window: ClosableWindow<LoadTextWindow>,
+        add_words_window: ClosableWindow<AddWordsWindow>,
+        add_custom_words_window: ClosableWindow<AddCustomWordsWindow>,
+
+        full_stats_window: ClosableWindow<FullStatsWindow>,
+        percentage_graph_window: ClosableWindow<PercentageGraphWindow>,
+        github_activity_window: ClosableWindow<GithubActivityWindow>,
+
+        import_window: ClosableWindow<ImportWindow>,
+        export_window: ClosableWindow<ExportWindow>,
+        settings_window: ClosableWindow<SettingsWindow>,
+        about_window: ClosableWindow<AboutWindow>,
+        search_words_window: ClosableWindow<SearchWordsWindow>,
+        edit_word_window: ClosableWindow<EditWordWindow>,
+        info_window: ClosableWindow<InfoWindow>,
+        synchronous_subtitles_window: ClosableWindow<SynchronousSubtitlesWindow>,
+        profiles_window: ClosableWindow<ProfilesWindow>,
+
+        /// Имя текущего профиля, пустое у профиля по умолчанию
+        profile: String,
+
+        /// Время последнего сохранения, нужно для автосохранения
+        last_save: f64,
+
+        /// Ошибка чтения сохранённых данных. Пока она есть, ничего не сохраняется, чтобы не затереть эти данные
+        load_error: Option<String>,
+
+        /// Текущая серия дней для нижней панели вместе с тем, для какой статистики она посчитана: количество дней и были ли попытки сегодня
+        streak: Option<(usize, bool, u32)>,
+    }
+
+    impl Program {
+        pub fn new(
+            words: Words,
+            settings: Settings,
+            mut stats: Statistics,
+            load_error: Option<String>,
+            today: Day,
+            working_time: f64,
+        ) -> Self {
+            stats.start_day(today, &words);
+            let learn_window = LearnWordsWindow::new(
+                &words,
+                today,
+                &settings,
+                &stats.by_day.get(&today).cloned().unwrap_or_default(),
+            );
+            let known_words = words.calculate_known_words();
+
+            let mut result = Self {
+                words,
+                settings,
+                stats,
+
+                known_words,
+                learn_window,
+                load_text_window: Default::default(),
+                add_words_window: Default::default(),
+                add_custom_words_window: Default::default(),
+
+                full_stats_window: Default::default(),
+                percentage_graph_window: Default::default(),
+                github_activity_window: Default::default(),
+
+                import_window: Default::default(),
+                export_window: Default::default(),
+                settings_window: Default::default(),
+                about_window: Default::default(),
+                search_words_window: Default::default(),
+                edit_word_window: Default::default(),
+                info_window: Default::default(),
+                synchronous_subtitles_window: Default::default(),
+                profiles_window: Default::default(),
+
+                profile: current_profile(),
+
+                last_save: now(),
+
+                load_error,
+
+                streak: None,
+            };
+
+            result.open_activity(today, working_time);
+
+            result
+        }
+
+        /// Текущая серия дней. Пересчитывается, только когда в статистике появляется новый день или первая попытка за сегодня, или когда статистика заменяется целиком.
+        fn current_streak(&mut self, today: Day) -> u32 {
+            let days = self.stats.by_day.len();
+            let active_today = self
+                .stats
+                .by_day
+                .get(&today)
+                .map(|x| x.attempts.right + x.attempts.wrong != 0)
+                .unwrap_or(false);
+            match self.streak {
+                Some((cached_days, cached_active, streak))
+                    if cached_days == days && cached_active == active_today =>
+                {
+                    streak
+                }
+                _ => {
+                    let (streak, _) = compute_streaks(&self.stats);
+                    self.streak = Some((days, active_today, streak));
+                    streak
+                }
+            }
+        }
+
+        pub fn get_settings(&self) -> &Settings {
+            &self.settings
+        }
+
+        pub fn window_size(&self) -> Vec2 {
+            self.settings.window_size()
+        }
+
+        /// Запоминает размер окна, на диск он попадёт при следующем сохранении
+        pub fn set_window_size(&mut self, size: Vec2) {
+            let size = (size.x.round(), size.y.round());
+            if size.0 > 0. && size.1 > 0. && self.settings.window_size != Some(size) {
+                self.settings.window_size = Some(size);
+            }
+        }
+
+        pub fn save_to_string(&mut self, today: Day, working_time: f64) -> String {
+            self.update_day_statistics(today, working_time);
+            ron::to_string(&SaveDataRef {
+                version: SAVE_VERSION,
+                words: &self.words,
+                settings: &self.settings,
+                stats: &self.stats,
+            })
+            .unwrap()
+        }
+
+        pub fn save(&mut self, today: Day, working_time: f64) {
+            self.last_save = now();
+            if self.load_error.is_some() {
+                return;
+            }
+            let data = self.save_to_string(today, working_time);
+            quad_storage::storage().set(&profile_key(&self.profile), &data);
+        }
+
+        pub fn load() -> Result<(Words, Settings, Statistics), ron::Error> {
+            Self::load_profile(&current_profile())
+        }
+
+        fn load_profile(profile: &str) -> Result<(Words, Settings, Statistics), ron::Error> {
+            let data = quad_storage::storage().get(&profile_key(profile));
+            match data {
+                Some(data) => Self::load_from_string(&data),
+                None => Ok(Default::default()),
+            }
+        }
+
+        #[cfg(not(target_arch = "wasm32"))]
+        fn restore_backup(&mut self, n: usize, today: Day, working_time: &mut f64) {
+            let result = quad_storage::storage().restore_backup(n);
+            if let Err(error) = result {
+                self.info_window = ClosableWindow::new(InfoWindow(vec![
+                    format!("Failed to restore backup {}.", n),
+                    error,
+                ]));
+                return;
+            }
+            let (words, settings, stats) = match Self::load() {
+                Ok(result) => result,
+                Err(error) => {
+                    self.info_window = ClosableWindow::new(InfoWindow(vec![
+                        format!("Backup {} can't be read.", n),
+                        format!("{:#?}", error),
+                    ]));
+                    return;
+                }
+            };
+            self.replace_data(words, settings, stats, today, working_time);
+            self.info_window =
+                ClosableWindow::new(InfoWindow(vec![format!("Backup {} is restored.", n)]));
+        }
+
+        /// Переносит работу на новый день. Время, набранное до смены дня, остаётся в статистике прошлого дня
+        pub fn change_day(&mut self, previous: Day, today: Day, working_time: &mut f64) {
+            self.save(previous, *working_time);
+            self.stats.start_day(today, &self.words);
+            *working_time = self
+                .stats
+                .by_day
+                .get(&today)
+                .map(|x| x.working_time)
+                .unwrap_or(0.);
+            self.learn_window.words_edited(
+                &self.words,
+                today,
+                &self.settings,
+                self.stats.by_day.entry(today).or_default(),
+            );
+            self.save(today, *working_time);
+        }
+
+        fn clean_trash(&mut self, today: Day, working_time: f64) {
+            let removed = self.words.purge_trash();
+            if let Some(window) = &mut self.search_words_window.0 {
+                window.update(&self.words, &self.settings);
+            }
+            self.learn_window.words_edited(
+                &self.words,
+                today,
+                &self.settings,
+                self.stats.by_day.entry(today).or_default(),
+            );
+            self.known_words = self.words.calculate_known_words();
+            self.save(today, working_time);
+            self.info_window = ClosableWindow::new(InfoWindow(vec![format!(
+                "Removed {} trash words.",
+                removed
+            )]));
+        }
+
+        /// Сохраняет текущий профиль и загружает другой
+        fn switch_profile(&mut self, profile: String, today: Day, working_time: &mut f64) {
+            let (words, settings, stats) = match Self::load_profile(&profile) {
+                Ok(result) => result,
+                Err(error) => {
+                    self.info_window = ClosableWindow::new(InfoWindow(vec![
+                        format!("Profile '{}' can't be read.", profile_name(&profile)),
+                        format!("{:#?}", error),
+                    ]));
+                    return;
+                }
+            };
+            self.save(today, *working_time);
+            quad_storage::storage().set(PROFILE_KEY, &profile);
+            self.profile = profile;
+            *working_time = 0.;
+            self.replace_data(words, settings, stats, today, working_time);
+            self.learn_window.cancel_learning();
+        }
+
+        fn replace_data(
+            &mut self,
+            words: Words,
+            settings: Settings,
+            stats: Statistics,
+            today: Day,
+            working_time: &mut f64,
+        ) {
+            self.words = words;
+            self.settings = settings;
+            self.stats = stats;
+            self.stats.start_day(today, &self.words);
+            self.streak = None;
+            self.load_error = None;
+            if let Some(time) = self.stats.by_day.get(&today).map(|x| x.working_time) {
+                *working_time = time;
+            }
+            self.known_words = self.words.calculate_known_words();
+            self.learn_window.update(
+                &self.words,
+                today,
+                &self.settings,
+                self.stats.by_day.entry(today).or_default(),
+            );
         }
 
         pub fn load_from_string(s: &str) -> Result<(Words, Settings, Statistics), ron::Error> {
-            ron::from_str::<(Words, Settings, Statistics)>(s)
+            let data = migrate(SaveData::from_str(s)?);
+            Ok((data.words, data.settings, data.stats))
         }
 
         pub fn update_day_statistics(&mut self, today: Day, working_time: f64) {
             let today = &mut self.stats.by_day.entry(today).or_default();
             today.working_time = working_time;
             today.word_count_by_level = self.words.calculate_word_statistics();
+            today.word_count_by_maturity = self.words.calculate_maturity_statistics(&self.settings);
         }
 
         pub fn open_activity(&mut self, today: Day, working_time: f64) {
@@ -1014,9 +3603,30 @@ mod gui {
             ctx: &CtxRef,
             today: Day,
             working_time: &mut f64,
-            rng: &mut Rand,
             paused: bool,
+            tex_allocator: &mut dyn epi::TextureAllocator,
         ) {
+            WindowsOrder::begin_frame(ctx);
+
+            let light = self.settings.theme.is_light();
+            if ctx.style().visuals.dark_mode == light {
+                ctx.set_visuals(if light {
+                    Visuals::light()
+                } else {
+                    Visuals::dark()
+                });
+            }
+            self.settings.white_theme = light;
+
+            ctx.set_pixels_per_point(self.settings.ui_scale);
+
+            // Сохраняет рабочее время и статистику дня, даже если долго ничего не проверялось
+            if self.settings.autosave_interval > 0.
+                && now() - self.last_save >= self.settings.autosave_interval
+            {
+                self.save(today, *working_time);
+            }
+
             TopBottomPanel::top("top").show(ctx, |ui| {
                 menu::bar(ui, |ui| {
                     menu::menu(ui, "Data", |ui| {
@@ -1026,15 +3636,64 @@ mod gui {
                             ));
                         }
                         if ui.button("Import").clicked() {
-                            self.import_window = ClosableWindow::new(ImportWindow::new());
+                            self.import_window =
+                                ClosableWindow::new(ImportWindow::new(&self.words, &self.stats));
+                        }
+                        if ui.button("Profiles").clicked() {
+                            self.profiles_window = ClosableWindow::new(ProfilesWindow::default());
+                        }
+                        if ui
+                            .button("Clean trash")
+                            .on_hover_text("Remove all words marked as trash")
+                            .clicked()
+                        {
+                            self.clean_trash(today, *working_time);
+                        }
+                        #[cfg(not(target_arch = "wasm32"))]
+                        {
+                            ui.separator();
+                            for n in 1..=quad_storage::BACKUPS_COUNT {
+                                if quad_storage::backup_exists(n)
+                                    && ui
+                                        .button(format!("Restore backup {}", n))
+                                        .on_hover_text("Backup 1 is the newest one")
+                                        .clicked()
+                                {
+                                    self.restore_backup(n, today, working_time);
+                                }
+                            }
+                        }
+                        ui.separator();
+                        if ui.button("Export to Anki").clicked() {
+                            self.export_window = ClosableWindow::new(ExportWindow::new(
+                                words_to_anki_tsv(&self.words),
+                            ));
+                        }
+                        if ui
+                            .button("Copy flashcards of due words")
+                            .on_hover_text("HTML page for printing")
+                            .clicked()
+                        {
+                            ui.output().copied_text = words_to_html(
+                                &self.words,
+                                WordFilter::Due {
+                                    today,
+                                    settings: &self.settings,
+                                },
+                            );
+                            self.info_window = ClosableWindow::new(InfoWindow(vec![
+                                "Flashcards are copied to the clipboard as HTML.".to_string(),
+                            ]));
                         }
                     });
                     menu::menu(ui, "Add words", |ui| {
                         if ui.button("From text").clicked() {
-                            self.load_text_window = ClosableWindow::new(LoadTextWindow::new(false));
+                            self.load_text_window =
+                                ClosableWindow::new(LoadTextWindow::new(TextFormat::Text));
                         }
                         if ui.button("From subtitles").clicked() {
-                            self.load_text_window = ClosableWindow::new(LoadTextWindow::new(true));
+                            self.load_text_window =
+                                ClosableWindow::new(LoadTextWindow::new(TextFormat::Srt));
                         }
                         if ui.button("Manually").clicked() {
                             self.add_custom_words_window = ClosableWindow::new(Default::default());
@@ -1046,11 +3705,15 @@ mod gui {
                         }
                     });
                     if ui.button("Search").clicked() {
-                        self.search_words_window =
-                            ClosableWindow::new(SearchWordsWindow::new(String::new(), &self.words));
+                        self.search_words_window = ClosableWindow::new(SearchWordsWindow::new(
+                            String::new(),
+                            &self.words,
+                            &self.settings,
+                        ));
                     }
                     menu::menu(ui, "Statistics", |ui| {
                         if ui.button("Full").clicked() {
+                            let (current_streak, longest_streak) = compute_streaks(&self.stats);
                             self.full_stats_window = ClosableWindow::new(FullStatsWindow {
                                 time: self
                                     .stats
@@ -1060,6 +3723,12 @@ mod gui {
                                     .sum::<f64>(),
                                 attempts: self.words.calculate_attempts_statistics(),
                                 word_count_by_level: self.words.calculate_word_statistics(),
+                                word_count_by_maturity: self
+                                    .words
+                                    .calculate_maturity_statistics(&self.settings),
+                                accuracy_by_level: self.words.accuracy_by_level(),
+                                current_streak,
+                                longest_streak,
                             });
                         }
                         if ui.button("GitHub-like").clicked() {
@@ -1089,8 +3758,11 @@ mod gui {
                                         "Right attempts".to_string(),
                                         "Wrong attempts".to_string(),
                                     ],
-                                    stackplot: false,
+                                    mode: GraphMode::Raw,
                                     moving: false,
+                                    show_weekday: false,
+                                    average: false,
+                                    average_days: 7,
                                 });
                         }
                         if ui.button("Time by day").clicked() {
@@ -1105,8 +3777,78 @@ mod gui {
                                         .map(|(k, v)| (*k, vec![v.working_time]))
                                         .collect(),
                                     names: vec!["Working time".to_string()],
-                                    stackplot: false,
+                                    mode: GraphMode::Raw,
+                                    moving: false,
+                                    show_weekday: false,
+                                    average: false,
+                                    average_days: 7,
+                                });
+                        }
+                        if ui.button("Words learned by day").clicked() {
+                            self.update_day_statistics(today, *working_time);
+                            self.percentage_graph_window =
+                                ClosableWindow::new(PercentageGraphWindow {
+                                    name: "Words learned by day",
+                                    values: self
+                                        .stats
+                                        .by_day
+                                        .iter()
+                                        .map(|(k, v)| (*k, vec![v.words_learned_today as f64]))
+                                        .collect(),
+                                    names: vec!["Learned words".to_string()],
+                                    mode: GraphMode::Raw,
+                                    moving: false,
+                                    show_weekday: false,
+                                    average: false,
+                                    average_days: 7,
+                                });
+                        }
+                        if ui.button("Projected reviews").clicked() {
+                            self.percentage_graph_window =
+                                ClosableWindow::new(PercentageGraphWindow {
+                                    name: "Projected reviews",
+                                    values: self
+                                        .words
+                                        .projected_reviews(today, 30, &self.settings)
+                                        .into_iter()
+                                        .map(|(k, v)| (k, vec![v as f64]))
+                                        .collect(),
+                                    names: vec!["Reviews if all answers are right".to_string()],
+                                    mode: GraphMode::Raw,
+                                    moving: false,
+                                    show_weekday: true,
+                                    average: false,
+                                    average_days: 7,
+                                });
+                        }
+                        if ui.button("Young and mature words by day").clicked() {
+                            self.update_day_statistics(today, *working_time);
+                            let kinds = [Maturity::Young, Maturity::Mature];
+                            self.percentage_graph_window =
+                                ClosableWindow::new(PercentageGraphWindow {
+                                    name: "Young and mature words by day",
+                                    values: self
+                                        .stats
+                                        .by_day
+                                        .iter()
+                                        .filter(|(_, v)| !v.word_count_by_maturity.is_empty())
+                                        .map(|(k, v)| {
+                                            let counts = kinds.iter().map(|x| {
+                                                v.word_count_by_maturity
+                                                    .get(x)
+                                                    .copied()
+                                                    .unwrap_or(0)
+                                                    as f64
+                                            });
+                                            (*k, counts.collect())
+                                        })
+                                        .collect(),
+                                    names: kinds.iter().map(|x| x.name().to_string()).collect(),
+                                    mode: GraphMode::Stacked,
                                     moving: false,
+                                    show_weekday: false,
+                                    average: false,
+                                    average_days: 7,
                                 });
                         }
                         if ui.button("Words by day").clicked() {
@@ -1149,12 +3891,22 @@ mod gui {
                                             Trash => "Trash".to_string(),
                                             Level(l) => format!("Level {}", l),
                                             Learned => "Learned".to_string(),
+                                            Suspended => "Suspended".to_string(),
                                         })
                                         .collect(),
-                                    stackplot: false,
+                                    mode: GraphMode::Raw,
                                     moving: false,
+                                    show_weekday: false,
+                                    average: false,
+                                    average_days: 7,
                                 });
                         }
+                        ui.separator();
+                        if ui.button("Export CSV").clicked() {
+                            self.update_day_statistics(today, *working_time);
+                            self.export_window =
+                                ClosableWindow::new(ExportWindow::new(stats_to_csv(&self.stats)));
+                        }
                     });
                     if ui.button("Settings").clicked() {
                         self.settings_window =
@@ -1166,15 +3918,61 @@ mod gui {
                 });
             });
 
+            let mut show_saved_data = false;
+            let mut overwrite_saved_data = false;
+            if let Some(error) = &self.load_error {
+                egui::Window::new("Failed to load data")
+                    .collapsible(false)
+                    .show(ctx, |ui| {
+                        ui.label("Saved data can't be read. Nothing will be saved until you decide what to do with it, so you can copy it, fix it and import it back.");
+                        ui.separator();
+                        ui.horizontal_wrapped(|ui| {
+                            ui.spacing_mut().item_spacing.x = 0.;
+                            ui.add(
+                                Label::new("Error: ")
+                                    .text_color(self.settings.color_error())
+                                    .monospace(),
+                            );
+                            ui.monospace(error);
+                        });
+                        ui.separator();
+                        ui.horizontal(|ui| {
+                            if ui.button("Show saved data").clicked() {
+                                show_saved_data = true;
+                            }
+                            if ui
+                                .add(
+                                    Button::new("Overwrite saved data")
+                                        .text_color(self.settings.color_delete()),
+                                )
+                                .on_hover_text("Saved data will be lost")
+                                .clicked()
+                            {
+                                overwrite_saved_data = true;
+                            }
+                        });
+                    });
+            }
+            if show_saved_data {
+                let data = quad_storage::storage()
+                    .get(&profile_key(&self.profile))
+                    .unwrap_or_default();
+                self.export_window = ClosableWindow::new(ExportWindow::new(data));
+            }
+            if overwrite_saved_data {
+                self.load_error = None;
+            }
+
             let mut save = false;
             self.learn_window.ui(
                 ctx,
                 &mut self.words,
                 today,
                 &mut self.stats.by_day.entry(today).or_default(),
+                *working_time,
                 &self.settings,
                 &mut save,
-                rng,
+                tex_allocator,
             );
             if save {
                 self.save(today, *working_time);
@@ -1199,10 +3997,16 @@ mod gui {
                         format!("Unique: {}", words.unique_words_count),
                         format!(
                             "Filtered: {}",
-                            stats.filtered_known + stats.filtered_learned
+                            stats.filtered_known
+                                + stats.filtered_learned
+                                + stats.filtered_rare
+                                + stats.filtered_stopwords
                         ),
                         format!("   Known: {}", stats.filtered_known),
                         format!("   Learning: {}", stats.filtered_learned),
+                        format!("   Rare: {}", stats.filtered_rare),
+                        format!("   Stopwords: {}", stats.filtered_stopwords),
+                        format!("Merged word forms: {}", stats.merged_forms),
                         format!("Unknown: {}", stats.unknown_words),
                     ]));
                     true
@@ -1211,34 +4015,117 @@ mod gui {
                 }
             });
 
-            let closed = self.import_window.ui(ctx, |t, ui| {
-                if let Some((words1, settings1, stats1)) = t.ui(ui, &self.settings) {
-                    self.words = words1;
-                    self.settings = settings1;
-                    self.stats = stats1;
-                    ui.ctx().set_pixels_per_point(self.settings.dpi);
-                    if let Some(time) = self.stats.by_day.get(&today).map(|x| x.working_time) {
-                        *working_time = time;
+            let closed = self
+                .import_window
+                .ui(ctx, |t, ui| match t.ui(ui, &self.settings) {
+                    Some(ImportAction::Replace(words1, settings1, stats1)) => {
+                        self.words = words1;
+                        self.settings = *settings1;
+                        self.stats = stats1;
+                        self.streak = None;
+                        self.load_error = None;
+                        if let Some(time) = self.stats.by_day.get(&today).map(|x| x.working_time) {
+                            *working_time = time;
+                        }
+                        true
                     }
-                    true
-                } else {
-                    false
-                }
-            });
+                    Some(ImportAction::Merge(words1, stats1)) => {
+                        self.words.merge(words1);
+                        self.stats.merge(stats1);
+                        self.streak = None;
+                        if let Some(time) = self.stats.by_day.get(&today).map(|x| x.working_time) {
+                            *working_time = time;
+                        }
+                        true
+                    }
+                    Some(ImportAction::AddWords(to_add, failed)) => {
+                        let day_stats = self.stats.by_day.entry(today).or_default();
+                        let mut imported = 0;
+                        let mut already_exists = 0;
+                        let mut deferred = 0;
+                        for (word, mut to_add) in to_add {
+                            // Существующие переводы не дублируются
+                            if let WordsToAdd::ToLearn { translations, .. } = &mut to_add {
+                                translations.retain(|x| !self.words.has_translation(&word, x));
+                                if translations.is_empty() {
+                                    already_exists += 1;
+                                    continue;
+                                }
+                            }
+                            if self.settings.new_words_limit_reached(day_stats) {
+                                deferred += 1;
+                            }
+                            self.words
+                                .add_word(word, to_add, today, day_stats, &self.settings);
+                            imported += 1;
+                        }
+                        let mut info = vec![
+                            format!("Imported rows: {}", imported),
+                            format!("Already existing rows: {}", already_exists),
+                        ];
+                        if deferred != 0 {
+                            info.push(format!(
+                                "Deferred to the next days due to daily limit: {}",
+                                deferred
+                            ));
+                        }
+                        if !failed.is_empty() {
+                            info.push(format!(
+                                "Failed to parse rows: {}",
+                                failed
+                                    .iter()
+                                    .map(|x| x.to_string())
+                                    .collect::<Vec<_>>()
+                                    .join(", ")
+                            ));
+                        }
+                        self.info_window = ClosableWindow::new(InfoWindow(info));
+                        true
+                    }
+                    None => false,
+                });
             if closed {
-                self.learn_window
-                    .update(&self.words, today, &self.settings.type_count, rng);
+                self.learn_window.update(
+                    &self.words,
+                    today,
+                    &self.settings,
+                    self.stats.by_day.entry(today).or_default(),
+                );
+                self.known_words = self.words.calculate_known_words();
+                self.save(today, *working_time);
             }
 
             let mut save = false;
+            let mut schedule_changed = false;
             self.settings_window.ui(ctx, |t, ui| {
-                t.ui(ui, &mut self.settings, &mut save);
+                t.ui(
+                    ui,
+                    &mut self.settings,
+                    &self.words,
+                    &mut save,
+                    &mut schedule_changed,
+                );
                 false
             });
+            if schedule_changed {
+                self.learn_window.update(
+                    &self.words,
+                    today,
+                    &self.settings,
+                    self.stats.by_day.entry(today).or_default(),
+                );
+            }
             if save {
                 self.save(today, *working_time);
             }
 
+            let new_limit_reached = self
+                .stats
+                .by_day
+                .get(&today)
+                .map(|x| self.settings.new_words_limit_reached(x))
+                .unwrap_or(false);
+
             let mut save = false;
             let closed = self.add_words_window.ui(ctx, |t, ui| {
                 if let Some((word, to_add, close)) = t.ui(
@@ -1246,12 +4133,15 @@ mod gui {
                     &mut self.search_words_window,
                     &mut self.synchronous_subtitles_window,
                     &self.words,
+                    &self.settings,
+                    new_limit_reached,
                 ) {
-                    self.words.add_word(
+                    t.skipped_duplicates = self.words.add_word(
                         word,
                         to_add,
                         today,
                         self.stats.by_day.entry(today).or_default(),
+                        &self.settings,
                     );
                     save = true;
                     close
@@ -1260,8 +4150,12 @@ mod gui {
                 }
             });
             if closed {
-                self.learn_window
-                    .update(&self.words, today, &self.settings.type_count, rng);
+                self.learn_window.update(
+                    &self.words,
+                    today,
+                    &self.settings,
+                    self.stats.by_day.entry(today).or_default(),
+                );
                 self.known_words = self.words.calculate_known_words();
                 self.save(today, *working_time);
             }
@@ -1271,20 +4165,25 @@ mod gui {
 
             let mut save = false;
             let closed = self.add_custom_words_window.ui(ctx, |t, ui| {
-                if let Some((word, to_add)) = t.ui(ui) {
-                    self.words.add_word(
+                if let Some((word, to_add)) = t.ui(ui, new_limit_reached) {
+                    t.skipped_duplicates = self.words.add_word(
                         word,
                         to_add,
                         today,
                         self.stats.by_day.entry(today).or_default(),
+                        &self.settings,
                     );
                     save = true;
                 }
                 false
             });
             if closed {
-                self.learn_window
-                    .update(&self.words, today, &self.settings.type_count, rng);
+                self.learn_window.update(
+                    &self.words,
+                    today,
+                    &self.settings,
+                    self.stats.by_day.entry(today).or_default(),
+                );
                 self.known_words = self.words.calculate_known_words();
                 self.save(today, *working_time);
             }
@@ -1302,16 +4201,30 @@ mod gui {
                 false
             });
 
+            let mut save = false;
             self.github_activity_window.ui(ctx, |t, ui| {
-                t.ui(ui, &self.settings);
+                t.ui(ui, &mut self.settings, &mut save);
                 false
             });
+            if save {
+                self.save(today, *working_time);
+            }
 
             self.about_window.ui(ctx, |t, ui| {
                 t.ui(ui);
                 false
             });
 
+            let mut switch_to = None;
+            let profile = &self.profile;
+            self.profiles_window.ui(ctx, |t, ui| {
+                switch_to = t.ui(ui, profile);
+                switch_to.is_some()
+            });
+            if let Some(profile) = switch_to {
+                self.switch_profile(profile, today, working_time);
+            }
+
             self.info_window.ui(ctx, |t, ui| {
                 t.ui(ui);
                 false
@@ -1328,10 +4241,34 @@ mod gui {
             });
 
             let mut edit_word = None;
+            let mut cram = None;
+            let mut words_changed = false;
             self.search_words_window.ui(ctx, |t, ui| {
-                edit_word = t.ui(ui, &self.words);
+                let result = t.ui(ui, &mut self.words, &self.settings, today);
+                edit_word = result.0;
+                cram = result.1;
+                words_changed = result.2;
                 false
             });
+            if words_changed {
+                self.learn_window.words_edited(
+                    &self.words,
+                    today,
+                    &self.settings,
+                    self.stats.by_day.entry(today).or_default(),
+                );
+                self.known_words = self.words.calculate_known_words();
+                self.save(today, *working_time);
+            }
+            if let Some(cram) = cram {
+                self.learn_window.start_cram(
+                    cram,
+                    &self.words,
+                    today,
+                    &self.settings,
+                    self.stats.by_day.entry(today).or_default(),
+                );
+            }
             if let Some(edit_word) = edit_word {
                 self.edit_word_window = ClosableWindow::new(EditWordWindow::new(edit_word));
             }
@@ -1339,15 +4276,23 @@ mod gui {
             let mut update_search = false;
             let mut save = false;
             let closed = self.edit_word_window.ui(ctx, |t, ui| {
-                let result = t.ui(ui, &mut self.words, &mut save, &self.settings);
+                let result = t.ui(ui, &mut self.words, &mut save, &self.settings, today);
                 update_search = result.1;
                 result.0
             });
             if update_search {
                 if let Some(window) = &mut self.search_words_window.0 {
-                    window.update(&self.words);
+                    window.update(&self.words, &self.settings);
                 }
             }
+            if closed || update_search || save {
+                self.learn_window.words_edited(
+                    &self.words,
+                    today,
+                    &self.settings,
+                    self.stats.by_day.entry(today).or_default(),
+                );
+            }
             if closed || update_search {
                 self.known_words = self.words.calculate_known_words();
                 self.save(today, *working_time);
@@ -1356,19 +4301,42 @@ mod gui {
                 self.save(today, *working_time);
             }
 
+            let streak = self.current_streak(today);
             egui::TopBottomPanel::bottom("bottom").show(ctx, |ui| {
                 let today = &self.stats.by_day.entry(today).or_default();
                 ui.monospace(format!(
-                    "Working time: {:6} | Attempts: {:4} | New words: {:4}{}",
+                    "Working time: {:6} | Attempts: {:4} | New words: {:4}{}{}",
                     print_time(*working_time),
                     today.attempts.right + today.attempts.wrong,
                     today.new_unknown_words_count,
+                    if streak > 1 {
+                        format!(" | 🔥 {}-day streak", streak)
+                    } else {
+                        String::new()
+                    },
                     if paused { "| PAUSED" } else { "" }
                 ));
+                if self.settings.daily_new_limit != 0 || self.settings.daily_review_limit != 0 {
+                    ui.monospace(format!(
+                        "New words limit: {} of {} used | Reviews limit: {} of {} used",
+                        today.new_unknown_words_count,
+                        print_limit(self.settings.daily_new_limit),
+                        today.reviewed_words_count,
+                        print_limit(self.settings.daily_review_limit),
+                    ));
+                }
             });
         }
     }
 
+    fn print_limit(limit: u64) -> String {
+        if limit == 0 {
+            "∞".to_string()
+        } else {
+            limit.to_string()
+        }
+    }
+
     fn print_time(time: f64) -> String {
         if time > 3600. {
             format!(
@@ -1384,19 +4352,49 @@ mod gui {
         }
     }
 
+    #[derive(Clone, Copy, PartialEq)]
+    enum TextFormat {
+        Text,
+        Srt,
+        Vtt,
+    }
+
+    impl TextFormat {
+        fn name(&self) -> &'static str {
+            match self {
+                TextFormat::Text => "Plain text",
+                TextFormat::Srt => "SRT subtitles",
+                TextFormat::Vtt => "WebVTT subtitles",
+            }
+        }
+    }
+
     struct LoadTextWindow {
-        load_subtitles: bool,
+        format: TextFormat,
+        /// Слова, которые встречаются в тексте реже, не предлагаются для добавления
+        min_occurrences: usize,
+        frequency_list: String,
         subtitles_error: Option<String>,
         text: String,
+        url: String,
+        download: Option<Download>,
+        /// Разбор большого текста, который идёт по частям на каждом кадре
+        extractor: Option<WordsExtractor>,
+        tokenize: TokenizeOptions,
+        /// Пропускать частые служебные слова и стоп-слова из настроек
+        skip_stopwords: bool,
+        /// Объединять формы одного слова, None — не объединять
+        merge_forms: Option<Lang>,
     }
 
     impl WindowTrait for LoadTextWindow {
         fn create_window(&self) -> Window<'static> {
-            Window::new(if self.load_subtitles {
-                "Words from subs"
-            } else {
+            Window::new(if self.format == TextFormat::Text {
                 "Words from text"
+            } else {
+                "Words from subs"
             })
+            .id(Id::new("load_text_window"))
             .vscroll(true)
             .fixed_size((200., 200.))
             .collapsible(false)
@@ -1407,16 +4405,133 @@ mod gui {
     struct LoadTextStats {
         filtered_known: usize,
         filtered_learned: usize,
+        filtered_rare: usize,
+        filtered_stopwords: usize,
+        merged_forms: usize,
         unknown_words: usize,
     }
 
     impl LoadTextWindow {
-        fn new(load_subtitles: bool) -> Self {
+        fn new(format: TextFormat) -> Self {
             Self {
-                load_subtitles,
+                format,
+                min_occurrences: 1,
+                frequency_list: String::new(),
                 subtitles_error: None,
                 text: String::new(),
+                url: String::new(),
+                download: None,
+                extractor: None,
+                tokenize: Default::default(),
+                skip_stopwords: false,
+                merge_forms: None,
+            }
+        }
+
+        fn url_ui(&mut self, ui: &mut Ui) {
+            let finished = self
+                .download
+                .as_ref()
+                .and_then(|download| download.lock().unwrap().take());
+            if let Some(result) = finished {
+                self.download = None;
+                match result {
+                    Ok(text) => {
+                        let url = self.url.to_lowercase();
+                        if url.ends_with(".srt") {
+                            self.format = TextFormat::Srt;
+                        } else if url.ends_with(".vtt") {
+                            self.format = TextFormat::Vtt;
+                        }
+                        self.text = text;
+                        self.subtitles_error = None;
+                    }
+                    Err(error) => self.subtitles_error = Some(error),
+                }
+            }
+            ui.horizontal(|ui| {
+                ui.label("URL:");
+                ui.add(TextEdit::singleline(&mut self.url).hint_text("https://..."));
+                if self.download.is_some() {
+                    ui.label("Downloading...");
+                    ui.ctx().request_repaint();
+                } else if ui
+                    .add_enabled(!self.url.trim().is_empty(), Button::new("Fetch"))
+                    .clicked()
+                {
+                    self.subtitles_error = None;
+                    self.download = Some(fetch_text(self.url.trim()));
+                }
+            });
+        }
+
+        /// Убирает уже известные и редкие слова и сортирует оставшиеся по списку частот
+        fn filter_words(
+            &self,
+            mut words: GetWordsResult,
+            data: &Words,
+            settings: &Settings,
+        ) -> (GetWordsResult, LoadTextStats) {
+            let mut stats = LoadTextStats::default();
+            // Слово, добавленное только в обратном направлении, есть лишь среди переводов других слов
+            let mut reverse: BTreeMap<&str, bool> = BTreeMap::new();
+            for status in data.0.values().flatten() {
+                if let Some(translation) = status.translation() {
+                    *reverse.entry(translation).or_default() |=
+                        matches!(status, WordStatus::ToLearn { .. });
+                }
+            }
+            words.words_with_context.0.retain(|x| {
+                let learning = match data.0.get(&x.0) {
+                    Some(x) => Some(x.iter().any(|x| matches!(x, WordStatus::ToLearn { .. }))),
+                    None => reverse.get(x.0.as_str()).copied(),
+                };
+                match learning {
+                    Some(true) => {
+                        stats.filtered_learned += 1;
+                        false
+                    }
+                    Some(false) => {
+                        stats.filtered_known += 1;
+                        false
+                    }
+                    None => {
+                        stats.unknown_words += 1;
+                        true
+                    }
+                }
+            });
+            if self.skip_stopwords {
+                words.words_with_context.0.retain(|x| {
+                    if is_stopword(&x.0, settings) {
+                        stats.filtered_stopwords += 1;
+                        stats.unknown_words -= 1;
+                        false
+                    } else {
+                        true
+                    }
+                });
+            }
+            if let Some(lang) = self.merge_forms {
+                stats.merged_forms = words.words_with_context.merge_forms(lang);
+                stats.unknown_words -= stats.merged_forms;
+            }
+            let min_occurrences = self.min_occurrences;
+            words.words_with_context.0.retain(|x| {
+                if x.1.len() >= min_occurrences {
+                    true
+                } else {
+                    stats.filtered_rare += 1;
+                    stats.unknown_words -= 1;
+                    false
+                }
+            });
+            if !self.frequency_list.trim().is_empty() {
+                words
+                    .words_with_context
+                    .sort_by_frequency(&parse_frequency_list(&self.frequency_list));
             }
+            (words, stats)
         }
 
         fn ui(
@@ -1426,48 +4541,301 @@ mod gui {
             settings: &Settings,
         ) -> Option<(GetWordsResult, LoadTextStats)> {
             let mut action = None;
+            ui.horizontal(|ui| {
+                ComboBox::from_id_source("text_format")
+                    .selected_text(self.format.name())
+                    .show_ui(ui, |ui| {
+                        for format in [TextFormat::Text, TextFormat::Srt, TextFormat::Vtt] {
+                            ui.selectable_value(&mut self.format, format, format.name());
+                        }
+                    });
+            });
+            ui.horizontal(|ui| {
+                ui.label("Min occurrences:");
+                ui.add(
+                    egui::DragValue::new(&mut self.min_occurrences)
+                        .clamp_range(1..=1000)
+                        .speed(0.1),
+                );
+            });
+            ui.checkbox(
+                &mut self.tokenize.hyphen_separates,
+                "Hyphen separates words",
+            )
+            .on_hover_text("Split 'mother-in-law' into 'mother', 'in' and 'law'");
+            ui.checkbox(
+                &mut self.tokenize.strip_apostrophes,
+                "Strip apostrophes around words",
+            )
+            .on_hover_text("Turn 'quoted' into quoted, but keep don't as is");
+            ui.checkbox(&mut self.skip_stopwords, "Skip stopwords")
+                .on_hover_text(
+                    "Common words like 'the' and 'and', and your own list from settings",
+                );
+            ui.horizontal(|ui| {
+                ui.label("Word forms:");
+                let selected = self.merge_forms.map(|x| x.name()).unwrap_or("Keep all");
+                ComboBox::from_id_source("merge_forms")
+                    .selected_text(selected)
+                    .show_ui(ui, |ui| {
+                        ui.selectable_value(&mut self.merge_forms, None, "Keep all");
+                        for lang in [Lang::English, Lang::Russian] {
+                            ui.selectable_value(&mut self.merge_forms, Some(lang), lang.name());
+                        }
+                    })
+                    .response
+                    .on_hover_text(
+                        "Merge forms like 'runs' and 'running'. Irregular forms stay apart",
+                    );
+            });
+            ui.horizontal(|ui| {
+                ui.checkbox(&mut self.tokenize.alphanumeric, "Words with digits")
+                    .on_hover_text("Keep tokens like covid19 and 2nd whole");
+                ui.add_enabled(
+                    self.tokenize.alphanumeric,
+                    Checkbox::new(&mut self.tokenize.skip_numbers, "Skip numbers"),
+                );
+            });
+            self.url_ui(ui);
+            ui.collapsing("Frequency list", |ui| {
+                ui.label("Paste words with their frequency in the language, one per line. Most common words will be offered first.");
+                ui.add(
+                    TextEdit::multiline(&mut self.frequency_list)
+                        .desired_rows(3)
+                        .hint_text("the 1000\nof 900\n..."),
+                );
+            });
+            if let Some(extractor) = &mut self.extractor {
+                if extractor.step(EXTRACT_BYTES_PER_FRAME) {
+                    let words = self.extractor.take().unwrap().finish();
+                    action = Some(self.filter_words(words, data, settings));
+                } else {
+                    ui.label("Extracting words...");
+                    ui.add(egui::ProgressBar::new(extractor.progress()).show_percentage());
+                    ui.ctx().request_repaint();
+                }
+            }
+            ui.horizontal(|ui| {
+                let button = ui.add_enabled(self.extractor.is_none(), Button::new("Use this text"));
+                if button.clicked() {
+                    let text = match self.format {
+                        TextFormat::Text => Some(self.text.clone()),
+                        TextFormat::Srt => match subtitles_text(&self.text) {
+                            Ok(text) => Some(text),
+                            Err(error) => {
+                                self.subtitles_error = Some(format!("{:#?}", error));
+                                None
+                            }
+                        },
+                        TextFormat::Vtt => match vtt_text(&self.text) {
+                            Ok(text) => Some(text),
+                            Err(error) => {
+                                self.subtitles_error = Some(error);
+                                None
+                            }
+                        },
+                    };
+                    if let Some(text) = text {
+                        if text.len() > LARGE_TEXT_SIZE {
+                            self.extractor = Some(WordsExtractor::new(
+                                text,
+                                settings.case_sensitive,
+                                self.tokenize,
+                            ));
+                        } else {
+                            let words = get_words(&text, settings.case_sensitive, self.tokenize);
+                            action = Some(self.filter_words(words, data, settings));
+                        }
+                    }
+                }
+            });
+            if let Some(error) = &self.subtitles_error {
+                ui.separator();
+                ui.horizontal_wrapped(|ui| {
+                    ui.spacing_mut().item_spacing.x = 0.;
+                    ui.add(
+                        Label::new("Error: ")
+                            .text_color(settings.color_error())
+                            .monospace(),
+                    );
+                    ui.monospace(error);
+                });
+            }
+            ui.separator();
+            ui.text_edit_multiline(&mut self.text);
+            action
+        }
+    }
+
+    struct ExportWindow {
+        text: String,
+    }
+
+    impl WindowTrait for ExportWindow {
+        fn create_window(&self) -> Window<'static> {
+            Window::new("Export data")
+                .vscroll(true)
+                .fixed_size((200., 200.))
+                .collapsible(false)
+        }
+    }
+
+    impl ExportWindow {
+        fn new(text: String) -> Self {
+            Self { text }
+        }
+
+        fn ui(&mut self, ui: &mut Ui) {
+            ui.label("Copy from this field: Ctrl+A, Ctrl+C.");
+
+            #[cfg(target_arch = "wasm32")]
+            if ui.button("Download as file").clicked() {
+                download_as_file(&self.text);
+            }
+
+            ui.text_edit_multiline(&mut self.text);
+        }
+    }
+
+    #[derive(Clone, Copy, PartialEq)]
+    enum ImportFormat {
+        Ron,
+        Csv,
+        Tsv,
+    }
+
+    impl ImportFormat {
+        fn name(&self) -> &'static str {
+            match self {
+                ImportFormat::Ron => "Exported data",
+                ImportFormat::Csv => "CSV: word, translation",
+                ImportFormat::Tsv => "TSV: word, translation",
+            }
+        }
+    }
+
+    enum ImportAction {
+        /// Заменить все данные
+        Replace(Words, Box<Settings>, Statistics),
+        /// Объединить с текущими данными, настройки остаются текущими
+        Merge(Words, Statistics),
+        /// Добавить слова, вторым значением идут номера строк, которые не удалось разобрать
+        AddWords(Vec<(String, WordsToAdd)>, Vec<usize>),
+    }
+
+    struct ImportWindow {
+        format: ImportFormat,
+        merge: bool,
+        text: String,
+        error: Option<String>,
+        /// Количество текущих слов и дней статистики, которые потеряются при замене
+        current: (usize, usize),
+        /// Разобранные данные, которые заменят текущие после подтверждения
+        confirm_replace: Option<(Words, Box<Settings>, Statistics)>,
+    }
+
+    impl WindowTrait for ImportWindow {
+        fn create_window(&self) -> Window<'static> {
+            Window::new("Import data")
+                .vscroll(true)
+                .fixed_size((200., 200.))
+                .collapsible(false)
+        }
+    }
+
+    impl ImportWindow {
+        fn new(words: &Words, stats: &Statistics) -> Self {
+            Self {
+                format: ImportFormat::Ron,
+                merge: false,
+                text: String::new(),
+                error: None,
+                current: (words.len(), stats.by_day.len()),
+                confirm_replace: None,
+            }
+        }
+
+        fn ui(&mut self, ui: &mut Ui, settings: &Settings) -> Option<ImportAction> {
+            let mut action = None;
+            if self.confirm_replace.is_some() {
+                ui.add(
+                    Label::new(format!(
+                        "You have {} words and {} days of stats; importing will replace them.",
+                        self.current.0, self.current.1
+                    ))
+                    .text_color(settings.color_delete())
+                    .wrap(true),
+                );
+                ui.horizontal(|ui| {
+                    if ui.button("Replace").clicked() {
+                        if let Some((words, settings, stats)) = self.confirm_replace.take() {
+                            action = Some(ImportAction::Replace(words, settings, stats));
+                        }
+                    }
+                    if ui
+                        .button("Merge")
+                        .on_hover_text(
+                            "Combine with current words and statistics, keeping current settings",
+                        )
+                        .clicked()
+                    {
+                        if let Some((words, _, stats)) = self.confirm_replace.take() {
+                            action = Some(ImportAction::Merge(words, stats));
+                        }
+                    }
+                    if ui.button("Cancel").clicked() {
+                        self.confirm_replace = None;
+                    }
+                });
+                return action;
+            }
+            ui.horizontal(|ui| {
+                ComboBox::from_id_source("import_format")
+                    .selected_text(self.format.name())
+                    .show_ui(ui, |ui| {
+                        for format in [ImportFormat::Ron, ImportFormat::Csv, ImportFormat::Tsv] {
+                            ui.selectable_value(&mut self.format, format, format.name());
+                        }
+                    });
+                if self.format == ImportFormat::Ron {
+                    ui.checkbox(&mut self.merge, "Merge")
+                        .on_hover_text("Combine with current words and statistics instead of replacing them. Current settings are kept");
+                }
+            });
             ui.horizontal(|ui| {
                 if ui.button("Use this text").clicked() {
-                    let text = &self.text;
-
-                    let words = if self.load_subtitles {
-                        match get_words_subtitles(text) {
-                            Ok(words) => Some(words),
+                    match self.format {
+                        ImportFormat::Ron => match Program::load_from_string(&self.text) {
+                            Ok((words, settings, stats)) => {
+                                if self.merge {
+                                    action = Some(ImportAction::Merge(words, stats));
+                                } else if self.current.0 == 0 {
+                                    action = Some(ImportAction::Replace(
+                                        words,
+                                        Box::new(settings),
+                                        stats,
+                                    ));
+                                } else {
+                                    self.confirm_replace = Some((words, Box::new(settings), stats));
+                                }
+                            }
                             Err(error) => {
-                                self.subtitles_error = Some(format!("{:#?}", error));
-                                None
+                                self.error = Some(format!("{:#?}", error));
                             }
+                        },
+                        ImportFormat::Csv | ImportFormat::Tsv => {
+                            let delimiter = if self.format == ImportFormat::Csv {
+                                ','
+                            } else {
+                                '\t'
+                            };
+                            let (words, failed) = words_from_delimited(&self.text, delimiter);
+                            action = Some(ImportAction::AddWords(words, failed));
                         }
-                    } else {
-                        Some(get_words(text))
-                    };
-                    if let Some(mut words) = words {
-                        let mut stats = LoadTextStats::default();
-                        words
-                            .words_with_context
-                            .0
-                            .retain(|x| match data.0.get(&x.0) {
-                                Some(x)
-                                    if x.iter()
-                                        .any(|x| matches!(x, WordStatus::ToLearn { .. })) =>
-                                {
-                                    stats.filtered_learned += 1;
-                                    false
-                                }
-                                Some(_) => {
-                                    stats.filtered_known += 1;
-                                    false
-                                }
-                                None => {
-                                    stats.unknown_words += 1;
-                                    true
-                                }
-                            });
-                        action = Some((words, stats));
                     }
                 }
             });
-            if let Some(error) = &self.subtitles_error {
+            if let Some(error) = &self.error {
                 ui.separator();
                 ui.horizontal_wrapped(|ui| {
                     ui.spacing_mut().item_spacing.x = 0.;
@@ -1485,301 +4853,781 @@ mod gui {
         }
     }
 
-    struct ExportWindow {
-        text: String,
+    struct SettingsWindow {
+        /// Названия и символы раскладок клавиатуры
+        layouts: Vec<(String, String)>,
+        want_to_use_keyboard_layout: bool,
+        /// Текст для проверки раскладок до их применения
+        layout_test: String,
+        /// Буквы слов, которых нет в применённой раскладке
+        unmapped_letters: BTreeSet<char>,
+        /// Тема, для которой добавляется отдельное расписание
+        new_schedule_tag: String,
+        info: Option<Result<String, String>>,
+        /// Масштаб, который сейчас перетаскивается. Применяется когда перетаскивание закончено, иначе поле убегает из-под мыши
+        ui_scale: Option<f32>,
+        /// Текст со своими стоп-словами, разбирается при изменении
+        stopwords: String,
     }
 
-    impl WindowTrait for ExportWindow {
+    impl WindowTrait for SettingsWindow {
         fn create_window(&self) -> Window<'static> {
-            Window::new("Export data")
-                .vscroll(true)
-                .fixed_size((200., 200.))
+            Window::new("Settings")
+                .vscroll(false)
+                .fixed_size((300., 100.))
                 .collapsible(false)
         }
     }
 
-    impl ExportWindow {
-        fn new(text: String) -> Self {
-            Self { text }
+    impl SettingsWindow {
+        fn new(settings: &Settings) -> Self {
+            let mut result = Self {
+                layouts: vec![
+                    ("First language".to_string(), String::new()),
+                    ("Second language".to_string(), String::new()),
+                ],
+                want_to_use_keyboard_layout: false,
+                layout_test: String::new(),
+                unmapped_letters: BTreeSet::new(),
+                new_schedule_tag: String::new(),
+                info: None,
+                ui_scale: None,
+                stopwords: tags_to_string(&settings.stopwords),
+            };
+            if settings.use_keyboard_layout {
+                let layout = &settings.keyboard_layout;
+                result.layouts = (0..layout.profiles.len())
+                    .map(|i| (layout.profiles[i].0.clone(), layout.profile_symbols(i)))
+                    .collect();
+            }
+            result
         }
 
-        fn ui(&mut self, ui: &mut Ui) {
-            ui.label("Copy from this field: Ctrl+A, Ctrl+C.");
+        fn ui(
+            &mut self,
+            ui: &mut Ui,
+            settings: &mut Settings,
+            words: &Words,
+            save: &mut bool,
+            schedule_changed: &mut bool,
+        ) {
+            ui.horizontal(|ui| {
+                ui.label("Theme: ");
+                for theme in [Theme::Dark, Theme::Light, Theme::System] {
+                    if ui
+                        .selectable_value(&mut settings.theme, theme, theme.name())
+                        .changed()
+                    {
+                        *save = true;
+                    }
+                }
+            });
 
-            #[cfg(target_arch = "wasm32")]
-            if ui.button("Download as file").clicked() {
-                download_as_file(&self.text);
+            ui.separator();
+
+            ui.horizontal(|ui| {
+                ui.label("Inaction time for pause: ");
+                if ui
+                    .add(
+                        egui::DragValue::new(&mut settings.time_to_pause)
+                            .speed(0.1)
+                            .clamp_range(0.0..=100.0)
+                            .min_decimals(0)
+                            .max_decimals(2),
+                    )
+                    .changed()
+                {
+                    *save = true;
+                }
+            });
+
+            ui.horizontal(|ui| {
+                ui.label("New day starts at: ");
+                if ui
+                    .add(
+                        egui::DragValue::new(&mut settings.day_start_hour)
+                            .clamp_range(0..=23)
+                            .suffix(":00"),
+                    )
+                    .on_hover_text("Local time, useful if you learn after midnight")
+                    .changed()
+                {
+                    *save = true;
+                }
+            });
+
+            ui.horizontal(|ui| {
+                let mut system = settings.timezone_offset.is_none();
+                if ui.checkbox(&mut system, "System timezone").changed() {
+                    settings.timezone_offset = if system {
+                        None
+                    } else {
+                        Some(timezone_offset_hours())
+                    };
+                    *save = true;
+                }
+                if let Some(offset) = &mut settings.timezone_offset {
+                    ui.label("UTC");
+                    if ui
+                        .add(
+                            egui::DragValue::new(offset)
+                                .clamp_range(-12.0..=14.0)
+                                .speed(0.25)
+                                .max_decimals(2),
+                        )
+                        .changed()
+                    {
+                        *save = true;
+                    }
+                }
+            });
+
+            ui.horizontal(|ui| {
+                ui.label("Autosave every: ");
+                if ui
+                    .add(
+                        egui::DragValue::new(&mut settings.autosave_interval)
+                            .speed(1.0)
+                            .clamp_range(0.0..=3600.0)
+                            .max_decimals(0)
+                            .suffix(" s"),
+                    )
+                    .on_hover_text("0 disables autosave")
+                    .changed()
+                {
+                    *save = true;
+                }
+            });
+
+            ui.horizontal(|ui| {
+                ui.label("Mouse movement to cancel pause: ");
+                if ui
+                    .add(
+                        egui::DragValue::new(&mut settings.pause_mouse_threshold)
+                            .speed(0.01)
+                            .clamp_range(0.0..=100.0)
+                            .min_decimals(2)
+                            .max_decimals(2),
+                    )
+                    .changed()
+                {
+                    *save = true;
+                }
+            });
+
+            ui.separator();
+
+            ui.horizontal(|ui| {
+                let scale_factor = 1.05;
+                ui.label("Scale: ");
+                let mut scale = self.ui_scale.unwrap_or(settings.ui_scale);
+                let response = ui.add(
+                    egui::DragValue::new(&mut scale)
+                        .clamp_range(0.5..=4.0)
+                        .speed(0.01)
+                        .max_decimals(2),
+                );
+                if response.dragged() {
+                    self.ui_scale = Some(scale);
+                } else if self.ui_scale.take().is_some() || response.changed() {
+                    settings.ui_scale = scale;
+                    *save = true;
+                }
+                if ui
+                    .add(egui::widgets::Button::new(" + ").text_style(egui::TextStyle::Monospace))
+                    .clicked()
+                {
+                    settings.ui_scale *= scale_factor;
+                    *save = true;
+                }
+                if ui
+                    .add(egui::widgets::Button::new(" - ").text_style(egui::TextStyle::Monospace))
+                    .clicked()
+                {
+                    settings.ui_scale /= scale_factor;
+                    *save = true;
+                }
+            });
+            if let Some(scale) = self.ui_scale {
+                // Размер в точках не зависит от масштаба, поэтому в пикселях он просто умножается на масштаб
+                let text_style = ui.style().body_text_style;
+                let height = ui.fonts().row_height(text_style) * scale;
+                ui.label(format!(
+                    "Preview: text will be {:.0} px high, {:.0}% of the current size",
+                    height,
+                    scale / settings.ui_scale * 100.
+                ));
             }
 
-            ui.text_edit_multiline(&mut self.text);
-        }
-    }
+            ui.separator();
+
+            ui.collapsing("Automatic change of keyboard layout", |ui| {
+                if !self.want_to_use_keyboard_layout && settings.use_keyboard_layout {
+                    self.want_to_use_keyboard_layout = true;
+                    *save = true;
+                }
+                ui.checkbox(
+                    &mut self.want_to_use_keyboard_layout,
+                    "Use automatic change of keyboard layout",
+                );
+                if self.want_to_use_keyboard_layout {
+                    ui.separator();
+                    ui.label("Type all letters on your keyboard in first field, and then in the same order symbols in the other fields. Newline is ignored. If you can't type some symbol, you can use space. Count of symbols except newline must be the same in all fields.");
+                    let mut remove = None;
+                    let can_remove = self.layouts.len() > 2;
+                    for (i, (name, symbols)) in self.layouts.iter_mut().enumerate() {
+                        ui.horizontal(|ui| {
+                            ui.add(TextEdit::singleline(name).desired_width(150.));
+                            if ui
+                                .add_enabled(can_remove, Button::new("Delete"))
+                                .clicked()
+                            {
+                                remove = Some(i);
+                            }
+                        });
+                        ui.text_edit_multiline(symbols);
+                    }
+                    if let Some(i) = remove {
+                        self.layouts.remove(i);
+                    }
+                    if ui.button("Add layout").clicked() {
+                        self.layouts.push((
+                            format!("Language {}", self.layouts.len() + 1),
+                            String::new(),
+                        ));
+                    }
+                    ui.separator();
+                    ui.horizontal(|ui| {
+                        ui.label("Test: ");
+                        ui.add(TextEdit::singleline(&mut self.layout_test).hint_text("Type in any layout"));
+                    });
+                    if !self.layout_test.is_empty() {
+                        match KeyboardLayout::new(&self.layouts) {
+                            Ok(layout) => {
+                                let from = layout.profile_of(&self.layout_test).unwrap_or(0);
+                                for (to, (name, _)) in layout.profiles.iter().enumerate() {
+                                    let mut text = self.layout_test.clone();
+                                    layout.change(from, to, &mut text);
+                                    ui.label(format!("{}: {}", name, text));
+                                }
+                            }
+                            Err(err) => {
+                                ui.add(Label::new(err).text_color(settings.color_error()));
+                            }
+                        }
+                    }
+                    ui.separator();
+                    if ui.button("Use this keyboard layout").clicked() {
+                        match KeyboardLayout::new(&self.layouts) {
+                            Ok(ok) => {
+                                self.unmapped_letters = ok.unmapped_letters(words);
+                                settings.use_keyboard_layout = true;
+                                settings.keyboard_layout = ok;
+                                self.info = Some(Ok("Used!".to_string()));
+                                *save = true;
+                            }
+                            Err(err) => {
+                                self.info = Some(Err(err));
+                            }
+                        }
+                    }
+                    if let Some(info) = &self.info {
+                        match info {
+                            Ok(ok) => {
+                                ui.label(ok);
+                            }
+                            Err(err) => {
+                                ui.horizontal_wrapped(|ui| {
+                                    ui.spacing_mut().item_spacing.x = 0.;
+                                    ui.add(Label::new("Error: ").text_color(settings.color_error()).monospace());
+                                    ui.monospace(err);
+                                });
+                            }
+                        }
+                    }
+                    if !self.unmapped_letters.is_empty() {
+                        let letters: String = self.unmapped_letters.iter().collect();
+                        ui.add(
+                            Label::new(format!(
+                                "Warning: letters '{}' from your words are not in any layout, they will not be changed.",
+                                letters
+                            ))
+                            .text_color(settings.color_error())
+                            .wrap(true),
+                        );
+                    }
+                } else {
+                    settings.use_keyboard_layout = false;
+                }
+            });
+
+            ui.separator();
+
+            ui.collapsing("Pronunciation", |ui| {
+                #[cfg(not(target_arch = "wasm32"))]
+                ui.horizontal(|ui| {
+                    ui.label("Command: ");
+                    if ui
+                        .add(
+                            TextEdit::singleline(&mut settings.speech_command)
+                                .hint_text("espeak -v {lang} {text}"),
+                        )
+                        .on_hover_text("{text} is replaced by the word and {lang} by its language. Leave empty to disable pronunciation")
+                        .changed()
+                    {
+                        *save = true;
+                    }
+                });
+                ui.label("Language of each keyboard layout, for example en or ru:");
+                let names: Vec<String> = if settings.keyboard_layout.profiles.is_empty() {
+                    vec!["All words".to_string()]
+                } else {
+                    settings
+                        .keyboard_layout
+                        .profiles
+                        .iter()
+                        .map(|(name, _)| name.clone())
+                        .collect()
+                };
+                if settings.speech_langs.len() < names.len() {
+                    settings.speech_langs.resize(names.len(), String::new());
+                }
+                for (name, lang) in names.iter().zip(settings.speech_langs.iter_mut()) {
+                    ui.horizontal(|ui| {
+                        ui.label(format!("{}: ", name));
+                        if ui.text_edit_singleline(lang).changed() {
+                            *save = true;
+                        }
+                    });
+                }
+            });
+
+            ui.separator();
+
+            ui.horizontal(|ui| {
+                ui.label("New words per day: ");
+                if ui
+                    .add(
+                        egui::DragValue::new(&mut settings.daily_new_limit)
+                            .speed(0.1)
+                            .clamp_range(0.0..=9999.0)
+                            .min_decimals(0)
+                            .max_decimals(0),
+                    )
+                    .on_hover_text("0 means no limit")
+                    .changed()
+                {
+                    *save = true;
+                }
+            });
+
+            ui.horizontal(|ui| {
+                ui.label("Words to repeat per day: ");
+                if ui
+                    .add(
+                        egui::DragValue::new(&mut settings.daily_review_limit)
+                            .speed(0.1)
+                            .clamp_range(0.0..=9999.0)
+                            .min_decimals(0)
+                            .max_decimals(0),
+                    )
+                    .on_hover_text("0 means no limit. Other words are left for the next days")
+                    .changed()
+                {
+                    *save = true;
+                    *schedule_changed = true;
+                }
+            });
 
-    struct ImportWindow {
-        text: String,
-        error: Option<String>,
-    }
+            ui.separator();
 
-    impl WindowTrait for ImportWindow {
-        fn create_window(&self) -> Window<'static> {
-            Window::new("Import data")
-                .vscroll(true)
-                .fixed_size((200., 200.))
-                .collapsible(false)
-        }
-    }
+            ui.horizontal(|ui| {
+                ui.label("Wrong answers in a row to reset word: ");
+                if ui
+                    .add(
+                        egui::DragValue::new(&mut settings.max_wrong_before_reset)
+                            .speed(0.1)
+                            .clamp_range(0.0..=99.0)
+                            .min_decimals(0)
+                            .max_decimals(0),
+                    )
+                    .on_hover_text("0 means never reset")
+                    .changed()
+                {
+                    *save = true;
+                }
+            });
 
-    impl ImportWindow {
-        fn new() -> Self {
-            Self {
-                text: String::new(),
-                error: None,
+            if ui
+                .checkbox(
+                    &mut settings.prioritize_hard_words,
+                    "Show words with many mistakes earlier",
+                )
+                .changed()
+            {
+                *save = true;
             }
-        }
 
-        fn ui(
-            &mut self,
-            ui: &mut Ui,
-            settings: &Settings,
-        ) -> Option<(Words, Settings, Statistics)> {
-            let mut action = None;
             ui.horizontal(|ui| {
-                if ui.button("Use this text").clicked() {
-                    match Program::load_from_string(&self.text) {
-                        Ok(result) => action = Some(result),
-                        Err(error) => {
-                            self.error = Some(format!("{:#?}", error));
-                        }
+                ui.label("Order of words: ");
+                for order in [
+                    SessionOrder::ReviewsFirst,
+                    SessionOrder::NewFirst,
+                    SessionOrder::Mixed,
+                ] {
+                    if ui
+                        .selectable_value(&mut settings.order, order, order.name())
+                        .changed()
+                    {
+                        *save = true;
                     }
                 }
             });
-            if let Some(error) = &self.error {
-                ui.separator();
-                ui.horizontal_wrapped(|ui| {
-                    ui.spacing_mut().item_spacing.x = 0.;
-                    ui.add(
-                        Label::new("Error: ")
-                            .text_color(settings.color_error())
-                            .monospace(),
-                    );
-                    ui.monospace(error);
-                });
-            }
-            ui.separator();
-            ui.text_edit_multiline(&mut self.text);
-            action
-        }
-    }
 
-    struct SettingsWindow {
-        lang1: String,
-        lang2: String,
-        want_to_use_keyboard_layout: bool,
-        info: Option<Result<String, String>>,
-    }
+            if ui
+                .checkbox(
+                    &mut settings.skip_choose,
+                    "Start learning all due words without choosing their count",
+                )
+                .changed()
+            {
+                *save = true;
+            }
 
-    impl WindowTrait for SettingsWindow {
-        fn create_window(&self) -> Window<'static> {
-            Window::new("Settings")
-                .vscroll(false)
-                .fixed_size((300., 100.))
-                .collapsible(false)
-        }
-    }
+            if ui
+                .checkbox(
+                    &mut settings.progressive_hints,
+                    "Show first letters of the answer for words with mistakes",
+                )
+                .on_hover_text("One more letter for every wrong answer in the past")
+                .changed()
+            {
+                *save = true;
+            }
 
-    impl SettingsWindow {
-        fn new(settings: &Settings) -> Self {
-            let mut result = Self {
-                lang1: String::new(),
-                lang2: String::new(),
-                want_to_use_keyboard_layout: false,
-                info: None,
-            };
-            if settings.use_keyboard_layout {
-                result.lang1 = settings.keyboard_layout.lang1.keys().copied().collect();
-                result.lang2 = settings.keyboard_layout.lang1.values().copied().collect();
+            if ui
+                .checkbox(&mut settings.mixed_directions, "Mixed directions")
+                .on_hover_text("Sometimes show the translation and ask for the word. The choice stays the same for a word during the day")
+                .changed()
+            {
+                *save = true;
             }
-            result
-        }
 
-        fn ui(&mut self, ui: &mut Ui, settings: &mut Settings, save: &mut bool) {
             ui.horizontal(|ui| {
-                ui.label("Theme: ");
-                if !settings.white_theme {
+                let mut limited = settings.card_time_limit.is_some();
+                if ui.checkbox(&mut limited, "Time limit per card").changed() {
+                    settings.card_time_limit = limited.then_some(30.);
+                    *save = true;
+                }
+                if let Some(limit) = &mut settings.card_time_limit {
                     if ui
-                        .add(Button::new("☀").frame(false))
-                        .on_hover_text("Switch to light mode")
-                        .clicked()
+                        .add(
+                            egui::DragValue::new(limit)
+                                .clamp_range(1.0..=600.0)
+                                .speed(0.5)
+                                .suffix(" s"),
+                        )
+                        .on_hover_text(
+                            "When the time is over, typed answers are checked automatically",
+                        )
+                        .changed()
                     {
-                        settings.white_theme = !settings.white_theme;
-                        ui.ctx().set_visuals(Visuals::light());
                         *save = true;
                     }
-                } else {
+                }
+            });
+
+            ui.separator();
+
+            if ui
+                .checkbox(&mut settings.case_sensitive, "Case sensitive words")
+                .changed()
+            {
+                *save = true;
+            }
+            if ui
+                .checkbox(&mut settings.accent_sensitive, "Accent sensitive check")
+                .on_hover_text("When disabled, 'café' and 'cafe' are the same answer")
+                .changed()
+            {
+                *save = true;
+            }
+
+            ui.horizontal(|ui| {
+                ui.label("Accepted typos in answer: ");
+                if ui
+                    .add(
+                        egui::DragValue::new(&mut settings.accept_typo_distance)
+                            .speed(0.1)
+                            .clamp_range(0.0..=5.0)
+                            .min_decimals(0)
+                            .max_decimals(0),
+                    )
+                    .on_hover_text("Not applied to words with one or two letters")
+                    .changed()
+                {
+                    *save = true;
+                }
+            });
+
+            ui.separator();
+
+            ui.horizontal(|ui| {
+                ui.label("Max context length when adding words: ");
+                if ui
+                    .add(
+                        egui::DragValue::new(&mut settings.context_max_length)
+                            .speed(1.0)
+                            .clamp_range(10.0..=1000.0)
+                            .min_decimals(0)
+                            .max_decimals(0),
+                    )
+                    .changed()
+                {
+                    *save = true;
+                }
+            });
+            ui.horizontal(|ui| {
+                ui.label("Context occurrences shown at once: ");
+                if ui
+                    .add(
+                        egui::DragValue::new(&mut settings.context_occurrences)
+                            .speed(1.0)
+                            .clamp_range(1.0..=1000.0)
+                            .min_decimals(0)
+                            .max_decimals(0),
+                    )
+                    .changed()
+                {
+                    *save = true;
+                }
+            });
+            ui.label("Own stopwords, skipped when adding words from text: ");
+            let response = ui.add(
+                TextEdit::multiline(&mut self.stopwords)
+                    .desired_rows(2)
+                    .hint_text("Comma or space separated"),
+            );
+            if response.changed() {
+                settings.stopwords = parse_stopwords(&self.stopwords);
+                *save = true;
+            }
+
+            ui.separator();
+
+            let color_delete = settings.color_delete();
+            let color_add = settings.color_add();
+            ui.collapsing("Repeats", |ui| {
+                if schedule_ui(ui, &mut settings.type_count, color_delete, color_add) {
+                    *save = true;
+                    *schedule_changed = true;
+                }
+            });
+
+            ui.collapsing("Repeats by tag", |ui| {
+                ui.label("Words with these tags use their own repeats instead of the ones above.");
+                let mut changed = false;
+                let mut delete = None;
+                for (tag, schedule) in settings.tag_schedules.iter_mut() {
+                    ui.collapsing(format!("Tag '{}'", tag), |ui| {
+                        if schedule_ui(ui, schedule, color_delete, color_add) {
+                            changed = true;
+                        }
+                        let button = Button::new("Delete these repeats").text_color(color_delete);
+                        if ui.add(button).clicked() {
+                            delete = Some(tag.clone());
+                        }
+                    });
+                }
+                if let Some(tag) = delete {
+                    settings.tag_schedules.remove(&tag);
+                    changed = true;
+                }
+                ui.horizontal(|ui| {
+                    ui.add(TextEdit::singleline(&mut self.new_schedule_tag).hint_text("Tag"));
+                    let tag = normalize_phrase(&self.new_schedule_tag);
+                    let can_add = !tag.is_empty() && !settings.tag_schedules.contains_key(&tag);
                     if ui
-                        .add(Button::new("🌙").frame(false))
-                        .on_hover_text("Switch to dark mode")
+                        .add_enabled(can_add, Button::new("Add repeats for tag"))
                         .clicked()
                     {
-                        settings.white_theme = !settings.white_theme;
-                        ui.ctx().set_visuals(Visuals::dark());
-                        *save = true;
+                        // Начинаем с общего расписания, чтобы его было удобно поправить
+                        settings
+                            .tag_schedules
+                            .insert(tag, settings.type_count.clone());
+                        self.new_schedule_tag.clear();
+                        changed = true;
                     }
+                });
+                if changed {
+                    *save = true;
+                    *schedule_changed = true;
                 }
             });
+        }
+    }
 
-            ui.separator();
-
+    /// Редактирование уровней расписания повторений, возвращает true, если оно изменилось
+    fn schedule_ui(
+        ui: &mut Ui,
+        schedule: &mut Vec<LearnType>,
+        color_delete: Color32,
+        color_add: Color32,
+    ) -> bool {
+        let mut delete = None;
+        let mut changed = false;
+        // Хотя бы один уровень должен остаться, иначе слова невозможно будет выучить
+        let can_delete = schedule.len() > 1;
+        for (pos, i) in schedule.iter_mut().enumerate() {
             ui.horizontal(|ui| {
-                ui.label("Inaction time for pause: ");
+                ui.label(format!("{}.", pos));
+                ui.separator();
+                ui.label("Wait days: ");
                 if ui
                     .add(
-                        egui::DragValue::new(&mut settings.time_to_pause)
+                        egui::DragValue::new(&mut i.wait_days)
                             .speed(0.1)
-                            .clamp_range(0.0..=100.0)
+                            .clamp_range(0.0..=99.0)
                             .min_decimals(0)
-                            .max_decimals(2),
+                            .max_decimals(0),
                     )
                     .changed()
                 {
-                    *save = true;
+                    changed = true;
+                }
+                ui.separator();
+                ui.label("Count: ");
+                if ui
+                    .add(
+                        egui::DragValue::new(&mut i.count)
+                            .speed(0.1)
+                            .clamp_range(1.0..=99.0)
+                            .min_decimals(0)
+                            .max_decimals(0),
+                    )
+                    .changed()
+                {
+                    changed = true;
+                }
+                ui.separator();
+                if ui.checkbox(&mut i.show_word, "Show hint").changed() {
+                    changed = true;
+                }
+                if ui
+                    .checkbox(&mut i.per_day, "Per day")
+                    .on_hover_text("One repeat per day with the wait between repeats, instead of all repeats at once")
+                    .changed()
+                {
+                    changed = true;
+                }
+                ui.separator();
+                if ui
+                    .add_enabled(can_delete, Button::new("Delete").text_color(color_delete))
+                    .clicked()
+                {
+                    delete = Some(pos);
                 }
             });
+        }
+        ui.separator();
+        if ui.add(Button::new("Add").text_color(color_add)).clicked() {
+            schedule.push(LearnType {
+                wait_days: 0,
+                count: 1,
+                show_word: false,
+                per_day: false,
+            });
+            changed = true;
+        }
+        if let Some(pos) = delete {
+            schedule.remove(pos);
+            changed = true;
+        }
+        changed
+    }
+
+    const DATA_KEY: &str = "learn_words_data";
+    const PROFILE_KEY: &str = "learn_words_profile";
+
+    /// Ключ с данными профиля. У профиля по умолчанию старый ключ, чтобы сохранённые раньше данные остались на месте
+    fn profile_key(profile: &str) -> String {
+        if profile.is_empty() {
+            DATA_KEY.to_owned()
+        } else {
+            format!("{}::{}", DATA_KEY, profile)
+        }
+    }
+
+    fn profile_name(profile: &str) -> &str {
+        if profile.is_empty() {
+            "Default"
+        } else {
+            profile
+        }
+    }
+
+    fn current_profile() -> String {
+        quad_storage::storage().get(PROFILE_KEY).unwrap_or_default()
+    }
+
+    /// Все профили с сохранёнными данными, профиль по умолчанию есть всегда
+    fn list_profiles() -> Vec<String> {
+        let storage = quad_storage::storage();
+        let prefix = format!("{}::", DATA_KEY);
+        let mut result: Vec<String> = (0..storage.len())
+            .filter_map(|i| storage.key(i))
+            .filter_map(|key| key.strip_prefix(&prefix).map(|x| x.to_owned()))
+            .collect();
+        result.push(String::new());
+        result.sort();
+        result.dedup();
+        result
+    }
+
+    #[derive(Default)]
+    struct ProfilesWindow {
+        new_profile: String,
+    }
+
+    impl WindowTrait for ProfilesWindow {
+        fn create_window(&self) -> Window<'static> {
+            Window::new("Profiles")
+                .vscroll(false)
+                .fixed_size((200., 100.))
+                .collapsible(false)
+        }
+    }
 
+    impl ProfilesWindow {
+        /// Возвращает профиль, на который надо переключиться
+        fn ui(&mut self, ui: &mut Ui, current: &str) -> Option<String> {
+            let mut result = None;
+            for profile in list_profiles() {
+                ui.horizontal(|ui| {
+                    if profile == current {
+                        ui.add(Label::new(profile_name(&profile)).strong());
+                        ui.label("(current)");
+                    } else {
+                        ui.label(profile_name(&profile));
+                        if ui.button("Switch").clicked() {
+                            result = Some(profile.clone());
+                        }
+                    }
+                });
+            }
             ui.separator();
-
             ui.horizontal(|ui| {
-                let scale_factor = 1.05;
-                ui.label(format!("Scale: {:.2}", settings.dpi));
-                if ui
-                    .add(egui::widgets::Button::new(" + ").text_style(egui::TextStyle::Monospace))
-                    .clicked()
-                {
-                    settings.dpi *= scale_factor;
-                    *save = true;
-                }
+                ui.add(TextEdit::singleline(&mut self.new_profile).hint_text("New profile"));
+                let name = self.new_profile.trim();
                 if ui
-                    .add(egui::widgets::Button::new(" - ").text_style(egui::TextStyle::Monospace))
+                    .add_enabled(!name.is_empty() && name != current, Button::new("Create"))
+                    .on_hover_text("Current data is saved, and the new profile starts empty")
                     .clicked()
                 {
-                    settings.dpi /= scale_factor;
-                    *save = true;
-                }
-                ui.ctx().set_pixels_per_point(settings.dpi);
-            });
-
-            ui.separator();
-
-            ui.collapsing("Automatic change of keyboard layout", |ui| {
-                if !self.want_to_use_keyboard_layout && settings.use_keyboard_layout {
-                    self.want_to_use_keyboard_layout = true;
-                    *save = true;
-                }
-                ui.checkbox(
-                    &mut self.want_to_use_keyboard_layout,
-                    "Use automatic change of keyboard layout",
-                );
-                if self.want_to_use_keyboard_layout {
-                    ui.separator();
-                    ui.label("Type all letters on your keyboard in first field, and then in the same order symbols in the second field. Newline is ignored. If you can't type some symbol, you can use space. Count of symbols except newline must be the same of both fields.");
-                    ui.label("First language:");
-                    ui.text_edit_multiline(&mut self.lang1);
-                    ui.label("Second language:");
-                    ui.text_edit_multiline(&mut self.lang2);
-                    if ui.button("Use this keyboard layout").clicked() {
-                        match KeyboardLayout::new(&self.lang1, &self.lang2) {
-                            Ok(ok) => {
-                                settings.use_keyboard_layout = true;
-                                settings.keyboard_layout = ok;
-                                self.info = Some(Ok("Used!".to_string()));
-                                *save = true;
-                            }
-                            Err(err) => {
-                                self.info = Some(Err(err));
-                            }
-                        }
-                    }
-                    if let Some(info) = &self.info {
-                        match info {
-                            Ok(ok) => {
-                                ui.label(ok);
-                            }
-                            Err(err) => {
-                                ui.horizontal_wrapped(|ui| {
-                                    ui.spacing_mut().item_spacing.x = 0.;
-                                    ui.add(Label::new("Error: ").text_color(settings.color_error()).monospace());
-                                    ui.monospace(err);
-                                });
-                            }
-                        }
-                    }
-                } else {
-                    settings.use_keyboard_layout = false;
-                }
-            });
-
-            ui.separator();
-
-            ui.collapsing("Repeats", |ui| {
-                let mut delete = None;
-                let color_delete = settings.color_delete();
-                let color_add = settings.color_add();
-                for (pos, i) in settings.type_count.iter_mut().enumerate() {
-                    ui.horizontal(|ui| {
-                        ui.label(format!("{}.", pos));
-                        ui.separator();
-                        ui.label("Wait days: ");
-                        if ui
-                            .add(
-                                egui::DragValue::new(&mut i.wait_days)
-                                    .speed(0.1)
-                                    .clamp_range(0.0..=99.0)
-                                    .min_decimals(0)
-                                    .max_decimals(0),
-                            )
-                            .changed()
-                        {
-                            *save = true;
-                        }
-                        ui.separator();
-                        ui.label("Count: ");
-                        if ui
-                            .add(
-                                egui::DragValue::new(&mut i.count)
-                                    .speed(0.1)
-                                    .clamp_range(0.0..=99.0)
-                                    .min_decimals(0)
-                                    .max_decimals(0),
-                            )
-                            .changed()
-                        {
-                            *save = true;
-                        }
-                        ui.separator();
-                        ui.checkbox(&mut i.show_word, "Show hint");
-                        ui.separator();
-                        if ui
-                            .add(Button::new("Delete").text_color(color_delete))
-                            .clicked()
-                        {
-                            delete = Some(pos);
-                        }
-                    });
-                }
-                ui.separator();
-                if ui.add(Button::new("Add").text_color(color_add)).clicked() {
-                    settings.type_count.push(LearnType {
-                        wait_days: 0,
-                        count: 1,
-                        show_word: false,
-                    });
-                    *save = true;
-                }
-                if let Some(pos) = delete {
-                    settings.type_count.remove(pos);
-                    *save = true;
+                    result = Some(name.to_owned());
                 }
             });
+            result
         }
     }
 
@@ -1839,13 +5687,62 @@ mod gui {
                 ui.add(egui::Label::new("Repository: ").strong());
                 ui.hyperlink("https://github.com/optozorax/learn_words");
             });
+            ui.separator();
+            ui.add(egui::Label::new("Keyboard:").strong());
+            for (keys, action) in [
+                (
+                    "Enter",
+                    "choose words to learn, check the answer, go to the next word",
+                ),
+                (
+                    "Backspace",
+                    "go to the previous field when the current one is empty",
+                ),
+                ("Esc", "close the topmost window"),
+                ("Alt+K or 1", "know this word, when adding words"),
+                ("Alt+T or 2", "trash word, when adding words"),
+                ("Alt+S", "skip word, when adding words"),
+                ("Ctrl+Enter", "add translations, when adding words"),
+            ] {
+                ui.horizontal_wrapped(|ui| {
+                    ui.spacing_mut().item_spacing.x = 0.;
+                    ui.add(egui::Label::new(keys).monospace());
+                    ui.label(format!(" — {}", action));
+                });
+            }
+        }
+    }
+
+    #[derive(Clone, Copy, PartialEq)]
+    enum SearchMode {
+        Fuzzy,
+        Substring,
+        Regex,
+    }
+
+    impl SearchMode {
+        fn name(&self) -> &'static str {
+            match self {
+                SearchMode::Fuzzy => "Fuzzy",
+                SearchMode::Substring => "Substring",
+                SearchMode::Regex => "Regex",
+            }
         }
     }
 
     struct SearchWordsWindow {
         search_string: String,
-        found_variants: Vec<String>,
+        mode: SearchMode,
+        /// Ошибка в регулярном выражении
+        error: Option<String>,
+        /// Найденные слова и перевод, по которому слово было найдено, если совпало не само слово
+        found_variants: Vec<(String, Option<String>)>,
         show_inners: bool,
+        search_translations: bool,
+        sort_by_due: bool,
+        /// Слова, отмеченные для массового редактирования
+        selected: BTreeSet<String>,
+        confirm_delete: bool,
     }
 
     impl WindowTrait for SearchWordsWindow {
@@ -1858,31 +5755,88 @@ mod gui {
     }
 
     impl SearchWordsWindow {
-        fn new(search_string: String, words: &Words) -> Self {
+        fn new(search_string: String, words: &Words, settings: &Settings) -> Self {
             let mut result = Self {
                 search_string,
+                mode: SearchMode::Fuzzy,
+                error: None,
                 found_variants: Vec::new(),
                 show_inners: false,
+                search_translations: false,
+                sort_by_due: false,
+                selected: BTreeSet::new(),
+                confirm_delete: false,
             };
-            result.update(words);
+            result.update(words, settings);
             result
         }
 
-        fn update_new(&mut self, search_string: String, words: &Words) {
+        fn update_new(&mut self, search_string: String, words: &Words, settings: &Settings) {
             if search_string != self.search_string {
                 self.search_string = search_string;
-                self.update(words);
+                self.update(words, settings);
             }
         }
 
-        fn update(&mut self, words: &Words) {
+        fn update(&mut self, words: &Words, settings: &Settings) {
             const ACCEPTED_LEVENSHTEIN: usize = 4;
+            self.error = None;
+            let regex = if self.mode == SearchMode::Regex {
+                match regex::Regex::new(&self.search_string) {
+                    Ok(regex) => Some(regex),
+                    Err(error) => {
+                        self.error = Some(error.to_string());
+                        self.found_variants.clear();
+                        return;
+                    }
+                }
+            } else {
+                None
+            };
+            let is_match = |text: &str| match &regex {
+                Some(regex) => regex.is_match(text),
+                None => text.contains(&self.search_string),
+            };
             let mut results = Vec::new();
-            for word in words.0.keys() {
-                let levenshtein = strsim::levenshtein(word, &self.search_string);
-                if levenshtein < ACCEPTED_LEVENSHTEIN {
-                    let jaro = strsim::jaro(word, &self.search_string);
-                    results.push((levenshtein, jaro, word.clone()));
+            for (word, statuses) in &words.0 {
+                let translations = statuses
+                    .iter()
+                    .filter_map(|x| x.translation())
+                    .filter(|_| self.search_translations)
+                    .map(|x| (x, Some(x)));
+                let mut best: Option<(usize, f64, Option<&str>)> = None;
+                for (text, translation) in
+                    std::iter::once((word.as_str(), None)).chain(translations)
+                {
+                    if self.mode == SearchMode::Fuzzy {
+                        let levenshtein = strsim::levenshtein(text, &self.search_string);
+                        if levenshtein < ACCEPTED_LEVENSHTEIN
+                            && best.map(|x| levenshtein < x.0).unwrap_or(true)
+                        {
+                            let jaro = strsim::jaro(text, &self.search_string);
+                            best = Some((levenshtein, jaro, translation));
+                        }
+                    } else {
+                        if is_match(text) {
+                            best = Some((0, 0., translation));
+                            break;
+                        }
+                    }
+                }
+                // Заметки ищутся только точным поиском, нечёткое сравнение с длинным текстом бессмысленно
+                if best.is_none()
+                    && self.mode != SearchMode::Fuzzy
+                    && statuses.iter().filter_map(|x| x.note()).any(is_match)
+                {
+                    best = Some((0, 0., None));
+                }
+                if let Some((levenshtein, jaro, translation)) = best {
+                    results.push((
+                        levenshtein,
+                        jaro,
+                        word.clone(),
+                        translation.map(|x| x.to_owned()),
+                    ));
                 }
             }
             results.sort_by(|a, b| {
@@ -1892,18 +5846,110 @@ mod gui {
                     a.0.cmp(&b.0)
                 }
             });
-            self.found_variants = results.into_iter().map(|(_, _, w)| w).collect();
+            self.found_variants = results.into_iter().map(|(_, _, w, t)| (w, t)).collect();
+            if self.sort_by_due {
+                // Слова без даты повторения идут в конце
+                self.found_variants.sort_by_key(|(word, _)| {
+                    let due = words.next_due_date(word, settings);
+                    (due.is_none(), due)
+                });
+            }
+            let found_variants = &self.found_variants;
+            self.selected
+                .retain(|word| found_variants.iter().any(|(found, _)| found == word));
+            if self.selected.is_empty() {
+                self.confirm_delete = false;
+            }
         }
 
-        fn find_word(this: &mut Option<Self>, search_string: String, words: &Words) {
+        fn find_word(
+            this: &mut Option<Self>,
+            search_string: String,
+            words: &Words,
+            settings: &Settings,
+        ) {
             if let Some(window) = this {
-                window.update_new(search_string, words);
+                window.update_new(search_string, words, settings);
+            } else {
+                *this = Some(Self::new(search_string, words, settings));
+            }
+        }
+
+        fn selection_ui(&mut self, ui: &mut Ui, words: &mut Words, settings: &Settings) -> bool {
+            let mut changed = false;
+            ui.horizontal(|ui| {
+                ui.label(format!("Selected: {}", self.selected.len()));
+                if ui.button("Select all").clicked() {
+                    self.selected = self.found_variants.iter().map(|x| x.0.clone()).collect();
+                }
+                if ui.button("Clear").clicked() {
+                    self.selected.clear();
+                    self.confirm_delete = false;
+                }
+            });
+            if self.selected.is_empty() {
+                return false;
+            }
+            if self.confirm_delete {
+                ui.horizontal(|ui| {
+                    ui.add(
+                        Label::new(format!("Delete {} words?", self.selected.len()))
+                            .text_color(settings.color_delete()),
+                    );
+                    if ui.button("Delete").clicked() {
+                        for word in &self.selected {
+                            if words.contains(word) {
+                                words.remove_word(word);
+                            }
+                        }
+                        changed = true;
+                    }
+                    if ui.button("Cancel").clicked() {
+                        self.confirm_delete = false;
+                    }
+                });
             } else {
-                *this = Some(Self::new(search_string, words));
+                ui.horizontal(|ui| {
+                    if ui.button("Mark as Trash").clicked() {
+                        for word in &self.selected {
+                            words.set_status(word, WordStatus::TrashWord);
+                        }
+                        changed = true;
+                    }
+                    if ui.button("Mark as Known").clicked() {
+                        for word in &self.selected {
+                            words.set_status(word, WordStatus::KnowPreviously);
+                        }
+                        changed = true;
+                    }
+                    if ui.button("Delete selected").clicked() {
+                        self.confirm_delete = true;
+                    }
+                    if ui
+                        .button("Copy as flashcards")
+                        .on_hover_text("HTML page for printing")
+                        .clicked()
+                    {
+                        ui.output().copied_text =
+                            words_to_html(words, WordFilter::Selected(&self.selected));
+                    }
+                });
+            }
+            if changed {
+                self.selected.clear();
+                self.update(words, settings);
             }
+            changed
         }
 
-        fn ui(&mut self, ui: &mut Ui, words: &Words) -> Option<String> {
+        /// Возвращает слово для редактирования, слова для зубрёжки и были ли изменены слова
+        fn ui(
+            &mut self,
+            ui: &mut Ui,
+            words: &mut Words,
+            settings: &Settings,
+            today: Day,
+        ) -> (Option<String>, Option<Vec<String>>, bool) {
             if ui
                 .add(
                     TextEdit::singleline(&mut self.search_string)
@@ -1911,18 +5957,68 @@ mod gui {
                 )
                 .changed()
             {
-                self.update(words);
+                self.update(words, settings);
             }
-            ui.checkbox(&mut self.show_inners, "Show inners");
+            let previous_mode = self.mode;
+            ComboBox::from_id_source("search_mode")
+                .selected_text(self.mode.name())
+                .show_ui(ui, |ui| {
+                    for mode in [SearchMode::Fuzzy, SearchMode::Substring, SearchMode::Regex] {
+                        ui.selectable_value(&mut self.mode, mode, mode.name());
+                    }
+                });
+            if previous_mode != self.mode {
+                self.update(words, settings);
+            }
+            if let Some(error) = &self.error {
+                ui.horizontal_wrapped(|ui| {
+                    ui.spacing_mut().item_spacing.x = 0.;
+                    ui.add(
+                        Label::new("Error: ")
+                            .text_color(settings.color_error())
+                            .monospace(),
+                    );
+                    ui.monospace(error);
+                });
+            }
+            let mut cram = None;
+            ui.horizontal(|ui| {
+                ui.checkbox(&mut self.show_inners, "Show inners");
+                if ui
+                    .checkbox(&mut self.search_translations, "Search translations")
+                    .changed()
+                {
+                    self.update(words, settings);
+                }
+                if ui
+                    .checkbox(&mut self.sort_by_due, "Sort by due date")
+                    .changed()
+                {
+                    self.update(words, settings);
+                }
+                if ui
+                    .add_enabled(
+                        !self.search_string.is_empty() && !self.found_variants.is_empty(),
+                        Button::new("Cram found"),
+                    )
+                    .on_hover_text("Repeat found words now, ignoring their schedule")
+                    .clicked()
+                {
+                    cram = Some(self.found_variants.iter().map(|x| x.0.clone()).collect());
+                }
+            });
+            let changed = !self.search_string.is_empty() && self.selection_ui(ui, words, settings);
             ui.separator();
             let mut edit_word = None;
             ScrollArea::vertical().max_height(200.0).show(ui, |ui| {
-                if self.search_string.is_empty() {
+                if words.is_empty() {
+                    ui.label("There are no words yet, add them from the \"Add words\" menu.");
+                } else if self.search_string.is_empty() {
                     if self.show_inners {
-                        for (n, (word, translations)) in words.0.iter().enumerate() {
+                        for (n, (word, translations)) in words.iter().enumerate() {
                             ui.with_layout(Layout::right_to_left(), |ui| {
                                 if ui.button("✏").on_hover_text("Edit").clicked() {
-                                    edit_word = Some(word.clone());
+                                    edit_word = Some(word.to_owned());
                                 }
                                 ui.with_layout(Layout::left_to_right(), |ui| {
                                     ui.heading(format!("{}. {}", n, word));
@@ -1932,6 +6028,7 @@ mod gui {
                                 ui.allocate_space(egui::vec2(1.0, 5.0));
                                 word_status_show_ui(word_status, ui);
                             }
+                            group_members_ui(ui, words, word);
                             ui.separator();
                         }
                     } else {
@@ -1947,45 +6044,97 @@ mod gui {
                         }
                     }
                 } else if self.show_inners {
-                    for (word, translations) in self
+                    for ((word, matched), translations) in self
                         .found_variants
                         .iter()
-                        .map(|x| (x, words.0.get(x).unwrap()))
+                        .map(|x| (x, words.get(&x.0).unwrap()))
                     {
                         ui.with_layout(Layout::right_to_left(), |ui| {
                             if ui.button("✏").on_hover_text("Edit").clicked() {
                                 edit_word = Some(word.clone());
                             }
+                            due_badge_ui(ui, words, word, today, settings);
                             ui.with_layout(Layout::left_to_right(), |ui| {
-                                ui.heading(word);
+                                selection_checkbox(ui, &mut self.selected, word);
+                                ui.heading(found_word_text(word, matched));
                             });
                         });
                         for word_status in translations {
                             ui.allocate_space(egui::vec2(1.0, 5.0));
                             word_status_show_ui(word_status, ui);
                         }
+                        group_members_ui(ui, words, word);
                         ui.separator();
                     }
                 } else {
-                    for word in &self.found_variants {
+                    for (word, matched) in &self.found_variants {
                         ui.with_layout(Layout::right_to_left(), |ui| {
                             if ui.button("✏").on_hover_text("Edit").clicked() {
                                 edit_word = Some(word.clone());
                             }
+                            due_badge_ui(ui, words, word, today, settings);
                             ui.with_layout(Layout::left_to_right(), |ui| {
-                                ui.label(word);
+                                selection_checkbox(ui, &mut self.selected, word);
+                                ui.label(found_word_text(word, matched));
                             });
                         });
                     }
                 }
             });
-            edit_word
+            (edit_word, cram, changed)
+        }
+    }
+
+    fn selection_checkbox(ui: &mut Ui, selected: &mut BTreeSet<String>, word: &str) {
+        let mut checked = selected.contains(word);
+        if ui.checkbox(&mut checked, "").changed() {
+            if checked {
+                selected.insert(word.to_owned());
+            } else {
+                selected.remove(word);
+            }
+        }
+    }
+
+    /// Цветная метка с датой следующего повторения: красная, если повторение просрочено, жёлтая, если слово надо повторить сегодня, зелёная, если слово выучено
+    fn due_badge_ui(ui: &mut Ui, words: &Words, word: &str, today: Day, settings: &Settings) {
+        let (text, color) = match words.next_due_date(word, settings) {
+            Some(day) if day.0 < today.0 => (
+                format!("overdue {}d", today.0 - day.0),
+                settings.color_error(),
+            ),
+            Some(day) if day.0 == today.0 => ("today".to_string(), settings.color_due_today()),
+            Some(day) => (
+                format!("in {}d", day.0 - today.0),
+                ui.visuals().text_color(),
+            ),
+            None => {
+                let learned = words
+                    .0
+                    .get(word)
+                    .map(|x| x.iter().any(|x| matches!(x, WordStatus::Learned { .. })))
+                    .unwrap_or(false);
+                if !learned {
+                    return;
+                }
+                ("learned".to_string(), settings.color_learned())
+            }
+        };
+        ui.add(Label::new(text).text_color(color).small());
+    }
+
+    /// Показывает, по какому переводу было найдено слово
+    fn found_word_text(word: &str, matched_translation: &Option<String>) -> String {
+        match matched_translation {
+            Some(translation) => format!("{} (translation: {})", word, translation),
+            None => word.to_string(),
         }
     }
 
     struct EditWordWindow {
         word: String,
         word_to_edit: String,
+        error: Option<String>,
     }
 
     impl WindowTrait for EditWordWindow {
@@ -2002,6 +6151,7 @@ mod gui {
             Self {
                 word: word.clone(),
                 word_to_edit: word,
+                error: None,
             }
         }
 
@@ -2011,8 +6161,19 @@ mod gui {
             words: &mut Words,
             save: &mut bool,
             settings: &Settings,
+            today: Day,
         ) -> (bool, bool) {
-            ui.label("Please not edit words while typing in learning words window!");
+            if let Some(day) = words.next_due_date(&self.word, settings) {
+                if day.0 > today.0 {
+                    ui.label(format!("Next review in {} days.", day.0 - today.0));
+                } else {
+                    ui.label("Next review: today.");
+                }
+            }
+            if let Some(error) = &self.error {
+                ui.add(Label::new(error).text_color(settings.color_error()));
+            }
+            group_members_ui(ui, words, &self.word);
             if let Some(getted) = words.0.get_mut(&self.word) {
                 let mut remove_word = false;
                 ui.with_layout(Layout::right_to_left(), |ui| {
@@ -2023,8 +6184,10 @@ mod gui {
                         remove_word = true;
                         *save = true;
                     }
+                    speak_button(ui, &self.word, settings);
                     ui.with_layout(Layout::left_to_right(), |ui| {
                         if ui.text_edit_singleline(&mut self.word_to_edit).changed() {
+                            self.error = None;
                             *save = true;
                         }
                     });
@@ -2034,7 +6197,7 @@ mod gui {
                 for (pos, word) in getted.iter_mut().enumerate() {
                     ui.separator();
                     let mut is_delete = false;
-                    if word_status_edit_ui(word, ui, &mut rename, &mut is_delete, settings) {
+                    if word_status_edit_ui(word, ui, &mut rename, &mut is_delete, settings, today) {
                         *save = true;
                     }
                     if is_delete {
@@ -2055,12 +6218,28 @@ mod gui {
                     }
                 }
                 if let Some((previous, new)) = rename {
-                    words.rename_word(&previous, &new);
+                    self.error = None;
+                    if let Err(error) = words.rename_word(&previous, &new) {
+                        // Возвращаем перевод обратно, иначе обратная ссылка потеряется
+                        for status in words.0.get_mut(&self.word).into_iter().flatten() {
+                            if let Some(translation) = status.translation_mut() {
+                                if *translation == new {
+                                    *translation = previous.clone();
+                                }
+                            }
+                        }
+                        self.error = Some(error);
+                    }
                 }
                 if self.word_to_edit != self.word {
-                    words.rename_word(&self.word, &self.word_to_edit);
-                    self.word = self.word_to_edit.clone();
-                    return (false, true);
+                    match words.rename_word(&self.word, &self.word_to_edit) {
+                        Ok(()) => {
+                            self.word = self.word_to_edit.clone();
+                            self.error = None;
+                            return (false, true);
+                        }
+                        Err(error) => self.error = Some(error),
+                    }
                 }
                 if remove_word {
                     words.remove_word(&self.word);
@@ -2078,7 +6257,15 @@ mod gui {
         words: WordsWithContext,
         translations: String,
         known_translations: String,
+        direction: LearnDirection,
+        /// Направление выбрано вручную, поэтому больше не подбирается по алфавитам слова и переводов
+        direction_touched: bool,
+        tags: String,
         previous: Option<(String, Vec<std::ops::Range<usize>>)>,
+        /// Сколько переводов было пропущено при последнем добавлении слова
+        skipped_duplicates: usize,
+        /// Сколько раз нажали «показать ещё» для контекста текущего слова
+        more_occurrences: usize,
     }
 
     impl WindowTrait for AddWordsWindow {
@@ -2097,7 +6284,12 @@ mod gui {
                 words,
                 translations: String::new(),
                 known_translations: String::new(),
+                direction: LearnDirection::Both,
+                direction_touched: false,
+                tags: String::new(),
                 previous: None,
+                skipped_duplicates: 0,
+                more_occurrences: 0,
             }
         }
 
@@ -2107,6 +6299,8 @@ mod gui {
             search_words_window: &mut ClosableWindow<SearchWordsWindow>,
             synchronous_subtitles_window: &mut ClosableWindow<SynchronousSubtitlesWindow>,
             words: &Words,
+            settings: &Settings,
+            new_limit_reached: bool,
         ) -> Option<(String, WordsToAdd, bool)> {
             ui.columns(2, |cols| {
                 let ui = &mut cols[0];
@@ -2117,6 +6311,7 @@ mod gui {
                     &mut search_words_window.0,
                     self.words.0[0].0.clone(),
                     words,
+                    settings,
                 );
                 SynchronousSubtitlesWindow::change_search_string(
                     &mut synchronous_subtitles_window.0,
@@ -2124,16 +6319,24 @@ mod gui {
                     true,
                 );
                 ui.separator();
+                let skip_pressed = {
+                    let input = ui.input();
+                    input.modifiers.alt && input.key_pressed(Key::S)
+                };
                 ui.horizontal(|ui| {
-                    if ui.button("Skip").clicked() {
+                    if ui.button("Skip").on_hover_text("Alt+S").clicked() || skip_pressed {
                         self.translations.clear();
                         self.known_translations.clear();
                         self.previous = Some(self.words.0.remove(0));
+                        self.more_occurrences = 0;
+                        self.direction_touched = false;
                     }
                     if let Some((text, ranges)) = &self.previous {
                         if ui.button(format!("Return ({})", text)).clicked() {
                             self.words.0.insert(0, (text.clone(), ranges.clone()));
                             self.previous = None;
+                            self.more_occurrences = 0;
+                            self.direction_touched = false;
                         }
                     } else {
                         ui.add_enabled(false, Button::new("Return previous"));
@@ -2144,12 +6347,19 @@ mod gui {
                     &mut self.words.0[0].0,
                     &mut self.translations,
                     &mut self.known_translations,
+                    &mut self.direction,
+                    &mut self.direction_touched,
+                    &mut self.tags,
+                    new_limit_reached,
                 ) {
                     self.translations.clear();
                     self.known_translations.clear();
                     self.previous = Some(self.words.0.remove(0));
+                    self.more_occurrences = 0;
+                    self.direction_touched = false;
                     action = Some((word, to_add, self.words.0.is_empty()));
                 }
+                duplicates_skipped_ui(ui, self.skipped_duplicates);
 
                 let ui = &mut cols[1];
                 ui.label("Context:");
@@ -2157,35 +6367,53 @@ mod gui {
                 if self.words.0.is_empty() {
                     return action;
                 }
+                // Для частых слов все вхождения создают слишком много виджетов, поэтому показываются не все
+                let page = settings.context_occurrences.max(1);
+                let total = self.words.0[0].1.len();
+                let shown = total.min(page * (self.more_occurrences + 1));
+                if shown < total {
+                    ui.label(format!("Shown {} of {} occurrences", shown, total));
+                }
                 ScrollArea::vertical().max_height(200.0).show(ui, |ui| {
-                    const CONTEXT_SIZE: usize = 50;
-                    for range in &self.words.0[0].1 {
-                        let mut start = range.start.saturating_sub(CONTEXT_SIZE);
-                        let mut end = {
-                            let result = range.end + CONTEXT_SIZE;
-                            if result > self.text.len() {
-                                self.text.len()
-                            } else {
-                                result
-                            }
-                        };
-                        while start > 0 && !self.text.is_char_boundary(start) {
-                            start -= 1;
-                        }
-                        while end < self.text.len() && !self.text.is_char_boundary(end) {
-                            end += 1;
-                        }
+                    for range in &self.words.0[0].1[..shown] {
+                        let range = snap_to_char_boundaries(&self.text, range.clone());
+                        let context = sentence_context(
+                            &self.text,
+                            range.clone(),
+                            settings.context_max_length,
+                        );
+                        let cut_start = self.text[..context.start]
+                            .chars()
+                            .next_back()
+                            .map(|c| !is_sentence_end(c))
+                            .unwrap_or(false);
+                        let cut_end = self.text[..context.end]
+                            .chars()
+                            .next_back()
+                            .map(|c| !is_sentence_end(c))
+                            .unwrap_or(false)
+                            && context.end < self.text.len();
                         ui.horizontal_wrapped(|ui| {
                             ui.spacing_mut().item_spacing.x = 0.;
-                            ui.label("...");
-                            ui.label(&self.text[start..range.start]);
+                            if cut_start {
+                                ui.label("...");
+                            }
+                            ui.label(self.text[context.start..range.start].trim_start());
                             ui.add(egui::Label::new(&self.text[range.clone()]).strong());
-                            ui.label(&self.text[range.end..end]);
-                            ui.label("...");
+                            ui.label(self.text[range.end..context.end].trim_end());
+                            if cut_end {
+                                ui.label("...");
+                            }
                         });
 
                         ui.separator();
                     }
+                    if shown < total {
+                        let more = page.min(total - shown);
+                        if ui.button(format!("Show {} more", more)).clicked() {
+                            self.more_occurrences += 1;
+                        }
+                    }
                 });
 
                 action
@@ -2198,6 +6426,12 @@ mod gui {
         word: String,
         translations: String,
         known_translations: String,
+        direction: LearnDirection,
+        /// Направление выбрано вручную, поэтому больше не подбирается по алфавитам слова и переводов
+        direction_touched: bool,
+        tags: String,
+        /// Сколько переводов было пропущено при последнем добавлении слова
+        skipped_duplicates: usize,
     }
 
     impl WindowTrait for AddCustomWordsWindow {
@@ -2210,7 +6444,7 @@ mod gui {
     }
 
     impl AddCustomWordsWindow {
-        fn ui(&mut self, ui: &mut Ui) -> Option<(String, WordsToAdd)> {
+        fn ui(&mut self, ui: &mut Ui, new_limit_reached: bool) -> Option<(String, WordsToAdd)> {
             let mut action = None;
             ui.separator();
             if let Some((word, to_add)) = word_to_add(
@@ -2218,21 +6452,37 @@ mod gui {
                 &mut self.word,
                 &mut self.translations,
                 &mut self.known_translations,
+                &mut self.direction,
+                &mut self.direction_touched,
+                &mut self.tags,
+                new_limit_reached,
             ) {
                 self.translations.clear();
                 self.known_translations.clear();
                 self.word.clear();
+                self.direction_touched = false;
                 action = Some((word, to_add));
             }
+            duplicates_skipped_ui(ui, self.skipped_duplicates);
             action
         }
     }
 
+    fn duplicates_skipped_ui(ui: &mut Ui, skipped_duplicates: usize) {
+        if skipped_duplicates != 0 {
+            ui.label(format!("{} duplicates skipped", skipped_duplicates));
+        }
+    }
+
     #[derive(Default)]
     struct FullStatsWindow {
         time: f64,
         attempts: TypingStats,
         word_count_by_level: BTreeMap<WordType, u64>,
+        word_count_by_maturity: BTreeMap<Maturity, u64>,
+        accuracy_by_level: BTreeMap<u8, TypingStats>,
+        current_streak: u32,
+        longest_streak: u32,
     }
 
     impl WindowTrait for FullStatsWindow {
@@ -2247,6 +6497,8 @@ mod gui {
     impl FullStatsWindow {
         fn ui(&mut self, ui: &mut Ui) {
             ui.label(format!("Full working time: {}", print_time(self.time)));
+            ui.label(format!("Current streak: {} days", self.current_streak));
+            ui.label(format!("Longest streak: {} days", self.longest_streak));
             ui.separator();
             ui.label(format!(
                 "Attempts: {}",
@@ -2263,18 +6515,55 @@ mod gui {
                     Trash => ui.label(format!("Trash: {}", count)),
                     Level(l) => ui.label(format!("Level {}: {}", l, count)),
                     Learned => ui.label(format!("Learned: {}", count)),
+                    Suspended => ui.label(format!("Suspended: {}", count)),
                 };
             }
+            ui.separator();
+            ui.label(format!(
+                "Words to learn by interval (mature from {} days):",
+                MATURE_INTERVAL_DAYS
+            ));
+            for (maturity, count) in &self.word_count_by_maturity {
+                ui.label(format!("{}: {}", maturity.name(), count));
+            }
+            ui.separator();
+            ui.label("Accuracy by level:");
+            for (level, stats) in &self.accuracy_by_level {
+                let attempts = stats.right + stats.wrong;
+                if attempts == 0 {
+                    ui.label(format!("Level {}: no attempts", level));
+                } else {
+                    ui.label(format!(
+                        "Level {}: {:.1}% over {} attempts",
+                        level,
+                        stats.right as f64 / attempts as f64 * 100.,
+                        attempts
+                    ));
+                }
+            }
         }
     }
 
+    #[derive(Default, Clone, Copy, PartialEq)]
+    enum GraphMode {
+        #[default]
+        Raw,
+        Stacked,
+        /// Значения за каждый день делятся на их сумму, так что сверху всегда 100%
+        Normalized,
+    }
+
     #[derive(Default)]
     struct PercentageGraphWindow {
         name: &'static str,
         values: BTreeMap<Day, Vec<f64>>,
         names: Vec<String>,
-        stackplot: bool,
+        mode: GraphMode,
         moving: bool,
+        show_weekday: bool,
+        /// Показывать скользящее среднее за `average_days` дней
+        average: bool,
+        average_days: u64,
     }
 
     impl WindowTrait for PercentageGraphWindow {
@@ -2286,36 +6575,75 @@ mod gui {
     impl PercentageGraphWindow {
         fn ui(&mut self, ui: &mut Ui) {
             ui.horizontal(|ui| {
-                ui.checkbox(&mut self.stackplot, "Stackplot");
+                ui.radio_value(&mut self.mode, GraphMode::Raw, "Raw");
+                ui.radio_value(&mut self.mode, GraphMode::Stacked, "Stackplot");
+                ui.radio_value(&mut self.mode, GraphMode::Normalized, "100% stacked");
+                ui.separator();
                 ui.checkbox(&mut self.moving, "Enable moving");
+                ui.checkbox(&mut self.show_weekday, "Day of week");
+            });
+            ui.horizontal(|ui| {
+                ui.checkbox(&mut self.average, "Moving average");
+                ui.add_enabled(
+                    self.average,
+                    DragValue::new(&mut self.average_days)
+                        .clamp_range(2..=365)
+                        .suffix(" days"),
+                );
             });
             use egui::plot::*;
             let mut max_value = 0.;
-            let lines = (0..self.values.values().next().unwrap().len()).map(|i| {
-                Line::new(Values::from_values(
+            let series: Vec<Vec<Value>> = (0..self.values.values().next().unwrap().len())
+                .map(|i| {
                     self.values
                         .iter()
-                        .map(|(day, arr)| {
-                            let value = if self.stackplot {
-                                arr.iter().take(i + 1).sum::<f64>()
-                            } else {
-                                arr[i]
+                        .filter_map(|(day, arr)| {
+                            let value = match self.mode {
+                                GraphMode::Raw => arr[i],
+                                GraphMode::Stacked => arr.iter().take(i + 1).sum::<f64>(),
+                                GraphMode::Normalized => {
+                                    let total = arr.iter().sum::<f64>();
+                                    if total == 0. {
+                                        return None;
+                                    }
+                                    arr.iter().take(i + 1).sum::<f64>() / total * 100.
+                                }
                             };
                             if value > max_value {
                                 max_value = value;
                             }
-                            Value::new(day.0 as f64, value)
+                            Some(Value::new(day.0 as f64, value))
                         })
-                        .collect(),
-                ))
-            });
+                        .collect()
+                })
+                .collect();
 
             let mut plot = Plot::new(format!("percentage {}", self.moving))
                 .allow_zoom(self.moving)
                 .allow_drag(self.moving)
+                .show_x(false)
                 .legend(Legend::default().position(Corner::LeftTop));
-            for (line, name) in lines.zip(self.names.iter()) {
-                plot = plot.line(line.name(name));
+            for (points, name) in series.iter().zip(self.names.iter()) {
+                plot = plot.line(Line::new(Values::from_values(points.clone())).name(name));
+            }
+            if self.average {
+                for (i, (points, name)) in series.iter().zip(self.names.iter()).enumerate() {
+                    let name = format!("{} ({}-day average)", name, self.average_days);
+                    // Тот же цвет, который Plot автоматически выдал исходной линии, чтобы все отрезки были одного цвета
+                    let golden_ratio = (5.0_f32.sqrt() - 1.0) / 2.0;
+                    let color: Color32 =
+                        egui::color::Hsva::new(i as f32 * golden_ratio, 0.85, 0.5, 1.0).into();
+                    // Отрезки с одинаковым именем показываются в легенде одной записью
+                    for segment in moving_average(points, self.average_days) {
+                        plot = plot.line(
+                            Line::new(Values::from_values(segment))
+                                .name(&name)
+                                .color(color)
+                                .width(2.)
+                                .style(LineStyle::dashed_loose()),
+                        );
+                    }
+                }
             }
 
             let min_day = self.values.keys().next().unwrap().0 as f64;
@@ -2330,10 +6658,52 @@ mod gui {
                 .width(0.)
                 .fill_alpha(0.005),
             );
+
+            // Plot не умеет форматировать подписи оси, поэтому даты рисуются текстом под графиком
+            const DATE_LABELS: u64 = 6;
+            let step = ((max_day - min_day) as u64 / DATE_LABELS).max(1);
+            let format = if self.show_weekday {
+                "%Y-%m-%d %a"
+            } else {
+                "%Y-%m-%d"
+            };
+            for day in (min_day as u64..=max_day as u64).step_by(step as usize) {
+                plot = plot.text(
+                    Text::new(
+                        Value::new(day as f64, 0.),
+                        date_from_day(Day(day)).format(format),
+                    )
+                    .anchor(Align2::CENTER_TOP),
+                );
+            }
             ui.add(plot);
         }
     }
 
+    /// Скользящее среднее за `days` дней. Дни без данных не учитываются, а если пропуск длиннее окна, то линия разрывается на отдельные отрезки.
+    fn moving_average(points: &[egui::plot::Value], days: u64) -> Vec<Vec<egui::plot::Value>> {
+        let days = days as f64;
+        let mut segments = Vec::new();
+        let mut current = Vec::new();
+        for (pos, point) in points.iter().enumerate() {
+            if pos != 0 && point.x - points[pos - 1].x > days {
+                segments.push(std::mem::take(&mut current));
+            }
+            let window: Vec<f64> = points[..=pos]
+                .iter()
+                .rev()
+                .take_while(|x| point.x - x.x < days)
+                .map(|x| x.y)
+                .collect();
+            let average = window.iter().sum::<f64>() / window.len() as f64;
+            current.push(egui::plot::Value::new(point.x, average));
+        }
+        if !current.is_empty() {
+            segments.push(current);
+        }
+        segments
+    }
+
     enum SynchronousSubtitlesWindow {
         Load {
             lang1: String,
@@ -2737,21 +7107,35 @@ mod gui {
         }
     }
 
+    #[derive(Default, Clone)]
     struct GithubDayData {
         attempts: u64,
         time: f64,
         new_unknown_words_count: u64,
     }
 
+    #[derive(Clone, Copy, PartialEq)]
+    enum ActivityBucket {
+        Day,
+        Week,
+        Month,
+    }
+
     struct GithubActivityWindow {
         max_day: Day,
         min_day: Day,
 
         data_by_day: BTreeMap<Day, GithubDayData>,
+        words_leveled: BTreeMap<Day, Vec<String>>,
+        /// Данные, просуммированные по неделям или месяцам. Ключ — первый день периода.
+        data_by_bucket: BTreeMap<Day, GithubDayData>,
         max_value: GithubDayData,
         min_value: GithubDayData,
 
         show: u8,
+        bucket: ActivityBucket,
+        /// День, на который нажали, о нём показывается подробная информация
+        selected_day: Option<Day>,
 
         show_day: Day,
         drag_delta: f32,
@@ -2766,13 +7150,6 @@ mod gui {
         }
     }
 
-    fn date_from_day(day: Day) -> chrono::Date<chrono::Utc> {
-        use chrono::TimeZone;
-        chrono::Utc
-            .timestamp(day.0 as i64 * 24 * 60 * 60 + 3600, 0)
-            .date()
-    }
-
     impl GithubActivityWindow {
         fn new(stats: &Statistics, today: Day) -> Self {
             let data_by_day: BTreeMap<Day, GithubDayData> = stats
@@ -2789,54 +7166,101 @@ mod gui {
                     )
                 })
                 .collect();
-            let min_value = GithubDayData {
-                attempts: data_by_day.values().map(|x| x.attempts).min().unwrap(),
-                time: data_by_day
+            let words_leveled = stats
+                .by_day
+                .iter()
+                .map(|(d, x)| (*d, x.words_leveled.clone()))
+                .collect();
+            let mut result = Self {
+                min_day: data_by_day.keys().next().copied().unwrap_or(today),
+                max_day: today,
+
+                data_by_day,
+                words_leveled,
+                data_by_bucket: BTreeMap::new(),
+                max_value: Default::default(),
+                min_value: Default::default(),
+
+                show: 0,
+                bucket: ActivityBucket::Day,
+                selected_day: None,
+
+                show_day: today,
+                drag_delta: 0.,
+            };
+            result.rebucket();
+            result
+        }
+
+        /// Первый день периода, в который попадает день
+        fn bucket_start(&self, day: Day) -> Day {
+            use chrono::Datelike;
+            let date = date_from_day(day);
+            match self.bucket {
+                ActivityBucket::Day => day,
+                ActivityBucket::Week => Day(day.0 - date.weekday().num_days_from_monday() as u64),
+                ActivityBucket::Month => Day(day.0 - date.day0() as u64),
+            }
+        }
+
+        /// Пересчитывает данные по периодам, а вместе с ними минимум и максимум для нормализации
+        fn rebucket(&mut self) {
+            let mut data_by_bucket: BTreeMap<Day, GithubDayData> = BTreeMap::new();
+            for (day, data) in &self.data_by_day {
+                let bucket = data_by_bucket.entry(self.bucket_start(*day)).or_default();
+                bucket.attempts += data.attempts;
+                bucket.time += data.time;
+                bucket.new_unknown_words_count += data.new_unknown_words_count;
+            }
+            if data_by_bucket.is_empty() {
+                // Нормализовать нечего, все клетки будут пустыми
+                self.min_value = Default::default();
+                self.max_value = Default::default();
+                self.data_by_bucket = data_by_bucket;
+                return;
+            }
+            self.min_value = GithubDayData {
+                attempts: data_by_bucket.values().map(|x| x.attempts).min().unwrap(),
+                time: data_by_bucket
                     .values()
                     .map(|x| x.time)
                     .min_by(|x, y| x.partial_cmp(y).unwrap())
                     .unwrap(),
-                new_unknown_words_count: data_by_day
+                new_unknown_words_count: data_by_bucket
                     .values()
                     .map(|x| x.new_unknown_words_count)
                     .min()
                     .unwrap(),
             };
-            let max_value = GithubDayData {
-                attempts: data_by_day.values().map(|x| x.attempts).max().unwrap(),
-                time: data_by_day
+            self.max_value = GithubDayData {
+                attempts: data_by_bucket.values().map(|x| x.attempts).max().unwrap(),
+                time: data_by_bucket
                     .values()
                     .map(|x| x.time)
                     .max_by(|x, y| x.partial_cmp(y).unwrap())
                     .unwrap(),
-                new_unknown_words_count: data_by_day
+                new_unknown_words_count: data_by_bucket
                     .values()
                     .map(|x| x.new_unknown_words_count)
                     .max()
                     .unwrap(),
             };
-            Self {
-                min_day: *data_by_day.keys().next().unwrap(),
-                max_day: today,
-
-                data_by_day,
-                max_value,
-                min_value,
-
-                show: 0,
-
-                show_day: today,
-                drag_delta: 0.,
-            }
+            self.data_by_bucket = data_by_bucket;
         }
 
         fn get_normalized_value(&self, day: Day) -> Option<f64> {
             fn normalize(min: f64, max: f64, v: f64) -> f64 {
-                (v - min) / (max - min)
+                // Если все значения одинаковые, например есть только один день, то показываем полный цвет
+                if max - min <= 0. {
+                    1.0
+                } else {
+                    (v - min) / (max - min)
+                }
             }
 
+            let day = self.bucket_start(day);
             match self.show {
-                0 => self.data_by_day.get(&day).map(|x| {
+                0 => self.data_by_bucket.get(&day).map(|x| {
                     normalize(
                         self.min_value.attempts as f64,
                         self.max_value.attempts as f64,
@@ -2844,10 +7268,10 @@ mod gui {
                     )
                 }),
                 1 => self
-                    .data_by_day
+                    .data_by_bucket
                     .get(&day)
                     .map(|x| normalize(self.min_value.time, self.max_value.time, x.time)),
-                _ => self.data_by_day.get(&day).map(|x| {
+                _ => self.data_by_bucket.get(&day).map(|x| {
                     normalize(
                         self.min_value.new_unknown_words_count as f64,
                         self.max_value.new_unknown_words_count as f64,
@@ -2858,25 +7282,100 @@ mod gui {
         }
 
         fn get_value_text(&self, day: Day) -> Option<String> {
-            self.data_by_day.get(&day).map(|x| {
-                format!(
-                    "Attempts: {}\nTime: {}\nNew words: {}\nTime for 1 attempt: {:.1}s",
-                    x.attempts,
-                    print_time(x.time),
-                    x.new_unknown_words_count,
-                    x.time / x.attempts as f64
-                )
-            })
+            self.data_by_bucket
+                .get(&self.bucket_start(day))
+                .map(Self::data_text)
         }
 
-        fn ui(&mut self, ui: &mut Ui, settings: &Settings) {
+        fn data_text(x: &GithubDayData) -> String {
+            format!(
+                "Attempts: {}\nTime: {}\nNew words: {}\nTime for 1 attempt: {:.1}s",
+                x.attempts,
+                print_time(x.time),
+                x.new_unknown_words_count,
+                x.time / x.attempts as f64
+            )
+        }
+
+        fn selected_day_ui(&mut self, ui: &mut Ui) {
+            let day = match self.selected_day {
+                Some(day) => day,
+                None => return,
+            };
+            ui.separator();
+            ui.horizontal(|ui| {
+                ui.strong(date_from_day(day).format("%Y-%m-%d").to_string());
+                if ui.small_button("✖").on_hover_text("Close").clicked() {
+                    self.selected_day = None;
+                }
+            });
+            match self.data_by_day.get(&day) {
+                Some(data) => {
+                    ui.label(Self::data_text(data));
+                }
+                None => {
+                    ui.label("No activity on this day.");
+                }
+            }
+            let words_leveled = self.words_leveled.get(&day).filter(|x| !x.is_empty());
+            if let Some(words_leveled) = words_leveled {
+                ui.label(format!("Words advanced a level ({}):", words_leveled.len()));
+                ScrollArea::vertical().max_height(100.0).show(ui, |ui| {
+                    ui.label(words_leveled.join(", "));
+                });
+            }
+        }
+
+        fn ui(&mut self, ui: &mut Ui, settings: &mut Settings, save: &mut bool) {
             ui.horizontal(|ui| {
                 ui.label("Show data about: ");
                 ui.selectable_value(&mut self.show, 0, "Attempts");
                 ui.selectable_value(&mut self.show, 1, "Working time");
                 ui.selectable_value(&mut self.show, 2, "New words");
             });
+            ui.horizontal(|ui| {
+                ui.label("Colors: ");
+                ComboBox::from_id_source("activity_palette")
+                    .selected_text(settings.activity_palette.name())
+                    .show_ui(ui, |ui| {
+                        for palette in [
+                            ActivityPalette::Green,
+                            ActivityPalette::Blue,
+                            ActivityPalette::Viridis,
+                        ] {
+                            if ui
+                                .selectable_value(
+                                    &mut settings.activity_palette,
+                                    palette,
+                                    palette.name(),
+                                )
+                                .changed()
+                            {
+                                *save = true;
+                            }
+                        }
+                    });
+                if ui
+                    .checkbox(&mut settings.activity_gamma, "Highlight small values")
+                    .changed()
+                {
+                    *save = true;
+                }
+            });
+            ui.horizontal(|ui| {
+                ui.label("Group by: ");
+                let previous = self.bucket;
+                ui.selectable_value(&mut self.bucket, ActivityBucket::Day, "Day");
+                ui.selectable_value(&mut self.bucket, ActivityBucket::Week, "Week");
+                ui.selectable_value(&mut self.bucket, ActivityBucket::Month, "Month");
+                if previous != self.bucket {
+                    self.rebucket();
+                }
+            });
             ui.separator();
+            if self.data_by_day.is_empty() {
+                ui.label("No activity yet.");
+            }
 
             let size = 8.;
             let margin = 1.5;
@@ -2890,7 +7389,7 @@ mod gui {
                 2. * margin + weeks as f32 * (size + margin) + weekday_size,
                 2. * margin + days as f32 * (size + margin) + month_size * 2.,
             );
-            let (rect, response) = ui.allocate_exact_size(desired_size, Sense::drag());
+            let (rect, response) = ui.allocate_exact_size(desired_size, Sense::click_and_drag());
 
             self.drag_delta += response.drag_delta().x;
             let offset_weeks = (self.drag_delta / (size + margin)) as i64;
@@ -2987,10 +7486,11 @@ mod gui {
                         settings.color_github_zero()
                     } else if let Some(value) = self.get_normalized_value(day) {
                         let zero_color = settings.color_github_zero();
-                        let min_color = settings.color_github_low();
-                        let max_color = settings.color_github_high();
+                        let palette = settings.activity_palette;
 
-                        let value = if settings.white_theme {
+                        let value = if !settings.activity_gamma {
+                            value as f32
+                        } else if settings.white_theme {
                             (value as f32).powf(0.7)
                         } else {
                             (value as f32).powf(0.71)
@@ -2998,16 +7498,14 @@ mod gui {
 
                         if value < 0.1 {
                             let value = value / 0.1;
+                            let min_color = activity_color(0., palette, settings.white_theme);
                             Color32::from(lerp(
                                 Rgba::from(zero_color)..=Rgba::from(min_color),
                                 value,
                             ))
                         } else {
                             let value = (value - 0.1) / (1.0 - 0.1);
-                            Color32::from(lerp(
-                                Rgba::from(min_color)..=Rgba::from(max_color),
-                                value,
-                            ))
+                            activity_color(value, palette, settings.white_theme)
                         }
                     } else {
                         ui.visuals().faint_bg_color
@@ -3020,6 +7518,9 @@ mod gui {
                     if let Some(pos) = response.hover_pos() {
                         rect.min -= margin2;
                         rect.max += margin2;
+                        if rect.contains(pos) && response.clicked() {
+                            self.selected_day = Some(day);
+                        }
                         if rect.contains(pos) && !response.dragged() {
                             let data = self.get_value_text(day);
                             let text = format!("{}-{}-{}", date.year(), date.month(), date.day())
@@ -3090,9 +7591,44 @@ mod gui {
                 TextStyle::Body,
                 ui.visuals().text_color(),
             );
+            self.selected_day_ui(ui);
         }
     }
 
+    /// Цвет ячейки календаря активности для значения от 0 до 1
+    fn activity_color(t: f32, palette: ActivityPalette, white_theme: bool) -> Color32 {
+        let stops: &[Color32] = match (palette, white_theme) {
+            (ActivityPalette::Green, true) => &[
+                Color32::from_rgb(155, 233, 168),
+                Color32::from_rgb(33, 110, 57),
+            ],
+            (ActivityPalette::Green, false) => {
+                &[Color32::from_rgb(5, 101, 5), Color32::from_rgb(0, 255, 128)]
+            }
+            (ActivityPalette::Blue, true) => &[
+                Color32::from_rgb(198, 219, 239),
+                Color32::from_rgb(8, 81, 156),
+            ],
+            (ActivityPalette::Blue, false) => &[
+                Color32::from_rgb(8, 48, 107),
+                Color32::from_rgb(66, 160, 255),
+            ],
+            (ActivityPalette::Viridis, _) => &[
+                Color32::from_rgb(68, 1, 84),
+                Color32::from_rgb(59, 82, 139),
+                Color32::from_rgb(33, 145, 140),
+                Color32::from_rgb(94, 201, 98),
+                Color32::from_rgb(253, 231, 37),
+            ],
+        };
+        let t = t.clamp(0., 1.) * (stops.len() - 1) as f32;
+        let pos = (t as usize).min(stops.len() - 2);
+        Color32::from(lerp(
+            Rgba::from(stops[pos])..=Rgba::from(stops[pos + 1]),
+            t - pos as f32,
+        ))
+    }
+
     struct ToTypeToday {
         all_words: Vec<String>,
         current_batch: Vec<String>,
@@ -3105,6 +7641,30 @@ mod gui {
 
         to_type_today: Option<ToTypeToday>,
         current: LearnWords,
+
+        /// Режим зубрёжки: слова повторяются независимо от расписания, а уровни не меняются
+        cram: bool,
+
+        /// Сколько раз слова перешли на следующий уровень за текущую сессию
+        session_level_ups: u64,
+        /// Сколько слов было выбрано в начале сессии
+        session_words: usize,
+        /// Попытки за день на момент начала сессии, попытки сессии считаются как разница с текущими
+        session_start_attempts: TypingStats,
+
+        /// Изучение было прервано вручную, поэтому выбор количества слов больше не пропускается сам
+        cancelled: bool,
+
+        /// Учить только слова с этими темами, если не пусто
+        tag_filter: BTreeSet<String>,
+        /// Учить только молодые или только зрелые слова
+        maturity_filter: Option<Maturity>,
+        available_tags: BTreeSet<String>,
+
+        images: WordImages,
+
+        /// Генератор для порядка слов в сессии, своё зерно позволяет воспроизвести порядок
+        rng: Rand,
     }
 
     enum LearnWords {
@@ -3123,6 +7683,10 @@ mod gui {
             words_to_guess: Vec<String>,
             max_types: u8,
             gain_focus: bool,
+            /// Когда карточка была показана, для ограничения времени ответа
+            started: f64,
+            /// Показывается единственный перевод, а вводить надо само слово
+            flipped: bool,
         },
         Checked {
             word: String,
@@ -3132,11 +7696,59 @@ mod gui {
             result: Vec<TypedWord>,
             max_types: u8,
             gain_focus: bool,
+            undo: Box<CheckUndo>,
+            /// Это старое слово, его надо учесть в дневном лимите повторений
+            review: bool,
+            /// Перевод, который был показан вместо слова
+            flipped: Option<String>,
         },
     }
 
+    /// Состояние до проверки слова, нужно чтобы отменить проверку
+    struct CheckUndo {
+        statuses: Vec<WordStatus>,
+        attempts: TypingStats,
+        words_leveled: usize,
+        words_learned_today: u64,
+        reviewed_words_count: u64,
+        session_level_ups: u64,
+        word_by_hint: Option<String>,
+        correct_answer: WordsToLearn,
+        words_to_type: Vec<String>,
+        words_to_guess: Vec<String>,
+    }
+
+    impl CheckUndo {
+        #[allow(clippy::too_many_arguments)]
+        fn new(
+            words: &Words,
+            word: &str,
+            day_stats: &DayStatistics,
+            session_level_ups: u64,
+            word_by_hint: &Option<String>,
+            correct_answer: &WordsToLearn,
+            words_to_type: &[String],
+            words_to_guess: &[String],
+        ) -> Self {
+            CheckUndo {
+                statuses: words.get(word).map(|x| x.to_vec()).unwrap_or_default(),
+                attempts: day_stats.attempts,
+                words_leveled: day_stats.words_leveled.len(),
+                words_learned_today: day_stats.words_learned_today,
+                reviewed_words_count: day_stats.reviewed_words_count,
+                session_level_ups,
+                word_by_hint: word_by_hint.clone(),
+                correct_answer: correct_answer.clone(),
+                words_to_type: words_to_type.to_vec(),
+                words_to_guess: words_to_guess.to_vec(),
+            }
+        }
+    }
+
     struct TypedWord {
         correct: bool,
+        /// Ответ засчитан, хотя в нём есть опечатка
+        typo: bool,
         translation: String,
         typed: String,
     }
@@ -3145,13 +7757,13 @@ mod gui {
         word: &str,
         words: &Words,
         today: Day,
-        type_count: &[LearnType],
+        settings: &Settings,
         mut f: impl FnMut(&str),
     ) {
         f(word);
-        if let Some(variants) = words.0.get(word) {
+        if let Some(variants) = words.get(word) {
             for i in variants {
-                if i.can_learn_today(today, type_count) {
+                if i.can_learn_today(today, settings.schedule_for(i)) {
                     if let WordStatus::ToLearn { translation, .. } = i {
                         f(translation);
                     }
@@ -3160,21 +7772,133 @@ mod gui {
         }
     }
 
+    /// Сравнивает введённые переводы с правильными как мультимножества: каждый правильный перевод засчитывается не больше стольких раз, сколько он встречается среди ответов. Неправильно введённым словам достаются оставшиеся переводы.
+    fn grade_guesses(answers: &[String], typed: &[String], settings: &Settings) -> Vec<TypedWord> {
+        let mut remaining = answers.to_vec();
+
+        // Сначала точные совпадения, чтобы опечатка не забрала перевод, который кто-то ввёл правильно
+        let mut matched: Vec<Option<(String, bool)>> = typed
+            .iter()
+            .map(|typed| {
+                remaining
+                    .iter()
+                    .position(|x| is_answer_correct(x, typed, settings))
+                    .map(|position| (remaining.remove(position), false))
+            })
+            .collect();
+
+        for (typed, matched) in typed.iter().zip(matched.iter_mut()) {
+            // Повторно введённый перевод — это не опечатка в другом переводе
+            let duplicate = answers
+                .iter()
+                .any(|x| is_answer_correct(x, typed, settings));
+            if matched.is_none() && !duplicate {
+                *matched = remaining
+                    .iter()
+                    .position(|x| is_answer_typo(x, typed, settings))
+                    .map(|position| (remaining.remove(position), true));
+            }
+        }
+
+        typed
+            .iter()
+            .zip(matched)
+            .map(|(typed, matched)| match matched {
+                Some((translation, typo)) => TypedWord {
+                    correct: true,
+                    typo,
+                    translation,
+                    typed: typed.clone(),
+                },
+                None => TypedWord {
+                    correct: false,
+                    typo: false,
+                    translation: remaining.remove(0),
+                    typed: typed.clone(),
+                },
+            })
+            .collect()
+    }
+
+    /// Состояние ввода слова. Если вводить нечего, то None.
+    fn typing_state(
+        word: String,
+        words: &Words,
+        today: Day,
+        settings: &Settings,
+        cram: bool,
+    ) -> Option<LearnWords> {
+        let max_types = if cram {
+            1
+        } else {
+            words.max_attempts_remains(&word, today, settings)
+        };
+        let result = words.get_word_to_learn(&word, today, settings, cram);
+        let words_to_type: Vec<String> = (0..result.words_to_type.len())
+            .map(|_| String::new())
+            .collect();
+        let words_to_guess: Vec<String> = (0..result.words_to_guess.len())
+            .map(|_| String::new())
+            .collect();
+        // Переворачивать можно только слово с одним угадываемым переводом, иначе непонятно, какой из них показать
+        let flipped = settings.mixed_directions
+            && words_to_type.is_empty()
+            && words_to_guess.len() == 1
+            && day_seed(&word, today) % 2 == 1;
+        if words_to_type.is_empty() && words_to_guess.is_empty() {
+            None
+        } else {
+            Some(LearnWords::Typing {
+                word,
+                word_by_hint: (!words_to_type.is_empty()).then(String::new),
+                correct_answer: result,
+                words_to_type,
+                max_types,
+                words_to_guess,
+                gain_focus: true,
+                started: now(),
+                flipped,
+            })
+        }
+    }
+
     impl LearnWordsWindow {
-        fn new(words: &Words, today: Day, type_count: &[LearnType], rng: &mut Rand) -> Self {
+        fn new(words: &Words, today: Day, settings: &Settings, day_stats: &DayStatistics) -> Self {
+            Self::with_seed(rng_seed(), words, today, settings, day_stats)
+        }
+
+        /// Окно с заданным зерном генератора, при одинаковом зерне слова выбираются в одинаковом порядке
+        fn with_seed(
+            seed: u64,
+            words: &Words,
+            today: Day,
+            settings: &Settings,
+            day_stats: &DayStatistics,
+        ) -> Self {
             let mut result = Self {
                 to_type_repeat: Vec::new(),
                 to_type_new: Vec::new(),
 
                 to_type_today: None,
                 current: LearnWords::None,
+                cram: false,
+                session_level_ups: 0,
+                session_words: 0,
+                session_start_attempts: TypingStats::default(),
+                cancelled: false,
+                tag_filter: BTreeSet::new(),
+                maturity_filter: None,
+                available_tags: BTreeSet::new(),
+                images: Default::default(),
+                rng: Rand::seed_from_u64(seed),
             };
-            result.update(words, today, type_count, rng);
+            result.update(words, today, settings, day_stats);
             result
         }
 
         fn cancel_learning(&mut self) {
             self.to_type_today = None;
+            self.cram = false;
             self.current = LearnWords::Choose {
                 all_repeat: self.to_type_repeat.len(),
                 all_new: self.to_type_new.len(),
@@ -3183,17 +7907,60 @@ mod gui {
             };
         }
 
-        fn pick_current_type(
+        fn start_session(&mut self, words_count: usize, day_stats: &DayStatistics) {
+            self.session_level_ups = 0;
+            self.session_words = words_count;
+            self.session_start_attempts = day_stats.attempts;
+        }
+
+        /// Попытки за текущую сессию
+        fn session_attempts(&self, day_stats: &DayStatistics) -> TypingStats {
+            // Если сессия началась вчера, то дневная статистика уже сброшена
+            TypingStats {
+                right: day_stats
+                    .attempts
+                    .right
+                    .saturating_sub(self.session_start_attempts.right),
+                wrong: day_stats
+                    .attempts
+                    .wrong
+                    .saturating_sub(self.session_start_attempts.wrong),
+            }
+        }
+
+        /// Начинает зубрёжку выбранных слов, каждое слово будет спрошено один раз
+        #[allow(clippy::too_many_arguments)]
+        fn start_cram(
             &mut self,
+            cram_words: Vec<String>,
             words: &Words,
             today: Day,
-            type_count: &[LearnType],
-            rng: &mut Rand,
+            settings: &Settings,
+            day_stats: &DayStatistics,
         ) {
+            let all_words: Vec<String> = cram_words
+                .into_iter()
+                .filter(|x| words.contains(x) && !words.is_learned(x))
+                .collect();
+            if all_words.is_empty() {
+                return;
+            }
+            self.cram = true;
+            self.start_session(all_words.len(), day_stats);
+            self.to_type_today = Some(ToTypeToday {
+                all_words,
+                current_batch: Vec::new(),
+            });
+            self.pick_current_type(words, today, settings);
+        }
+
+        fn pick_current_type(&mut self, words: &Words, today: Day, settings: &Settings) {
             if let Some(to_type_today) = &mut self.to_type_today {
-                to_type_today
-                    .all_words
-                    .retain(|x| words.can_learn_today(x, today, type_count));
+                if !self.cram {
+                    to_type_today
+                        .all_words
+                        .retain(|x| words.can_learn_today(x, today, settings));
+                }
             }
 
             loop {
@@ -3206,6 +7973,7 @@ mod gui {
                         .unwrap_or(true)
                 {
                     self.current = LearnWords::None;
+                    self.cram = false;
                     return;
                 }
 
@@ -3216,79 +7984,215 @@ mod gui {
                     .unwrap_or(false)
                 {
                     self.to_type_today = None;
+                    self.cram = false;
+                }
+
+                if let Some(to_type_today) = &mut self.to_type_today {
+                    if to_type_today.current_batch.is_empty() {
+                        // Сначала берутся слова нужного вида, остальные — когда они закончатся
+                        let candidates = match settings.order {
+                            SessionOrder::Mixed => to_type_today.all_words.clone(),
+                            SessionOrder::ReviewsFirst | SessionOrder::NewFirst => {
+                                let (new, reviews): (Vec<_>, Vec<_>) = to_type_today
+                                    .all_words
+                                    .iter()
+                                    .cloned()
+                                    .partition(|x| words.is_new(x));
+                                let (first, rest) = if settings.order == SessionOrder::NewFirst {
+                                    (new, reviews)
+                                } else {
+                                    (reviews, new)
+                                };
+                                if first.is_empty() {
+                                    rest
+                                } else {
+                                    first
+                                }
+                            }
+                        };
+                        let (hint_words, guess_words): (Vec<_>, Vec<_>) = candidates
+                            .into_iter()
+                            .partition(|x| words.has_hint(x, settings));
+
+                        if hint_words.is_empty() {
+                            to_type_today.current_batch = guess_words;
+                        } else {
+                            to_type_today.current_batch = hint_words;
+                        }
+
+                        to_type_today.current_batch.shuffle(&mut self.rng);
+                    }
+
+                    let position = if settings.prioritize_hard_words {
+                        // Чем чаще слово вводится неправильно, тем больше шанс что оно будет следующим
+                        WeightedIndex::new(
+                            to_type_today
+                                .current_batch
+                                .iter()
+                                .map(|x| words.difficulty(x)),
+                        )
+                        .map(|dist| dist.sample(&mut self.rng))
+                        .unwrap_or(0)
+                    } else {
+                        0
+                    };
+                    let word = to_type_today.current_batch.remove(position);
+                    if self.cram {
+                        to_type_today.all_words.retain(|x| *x != word);
+                    }
+                    if !words.is_learned(&word) {
+                        if let Some(typing) =
+                            typing_state(word.clone(), words, today, settings, self.cram)
+                        {
+                            self.current = typing;
+                            return;
+                        }
+                        to_type_today.all_words.retain(|x| *x != word);
+                        to_type_today.current_batch.retain(|x| *x != word);
+                    } else {
+                        to_type_today.all_words.retain(|x| *x != word);
+                        to_type_today.current_batch.retain(|x| *x != word);
+                    }
+                } else {
+                    self.cancel_learning();
+                    return;
+                }
+            }
+        }
+
+        /// Начинает изучение выбранного количества старых и новых слов, `usize::MAX` означает все слова
+        #[allow(clippy::too_many_arguments)]
+        fn choose_words(
+            &mut self,
+            n_repeat: usize,
+            n_new: usize,
+            words: &Words,
+            today: Day,
+            settings: &Settings,
+            day_stats: &DayStatistics,
+        ) {
+            let to_type_repeat = &mut self.to_type_repeat;
+            let to_type_new = &mut self.to_type_new;
+            let mut result = BTreeSet::new();
+
+            while result.len() < n_repeat && !to_type_repeat.is_empty() {
+                let first = to_type_repeat[0].clone();
+                select_with_translations(&first.0, words, today, settings, |word| {
+                    to_type_repeat.retain(|x| x.0 != word);
+                    result.insert(word.to_string());
+                });
+            }
+
+            let n_repeat = result.len();
+
+            while result.len() < n_repeat.saturating_add(n_new) && !to_type_new.is_empty() {
+                let first = to_type_new[0].clone();
+                select_with_translations(&first.0, words, today, settings, |word| {
+                    to_type_new.retain(|x| x.0 != word);
+                    result.insert(word.to_string());
+                });
+            }
+
+            self.start_session(result.len(), day_stats);
+            self.cancelled = false;
+            self.to_type_today = Some(ToTypeToday {
+                all_words: result.into_iter().collect(),
+                current_batch: Vec::new(),
+            });
+
+            self.pick_current_type(words, today, settings);
+        }
+
+        fn update(
+            &mut self,
+            words: &Words,
+            today: Day,
+            settings: &Settings,
+            day_stats: &DayStatistics,
+        ) {
+            self.update_lists(words, today, settings, day_stats);
+            self.pick_current_type(words, today, settings);
+        }
+
+        /// Вызывается, когда слова были изменены в другом окне. Если текущее слово было изменено или удалено, то оно выбирается заново, иначе ввод не сбрасывается.
+        fn words_edited(
+            &mut self,
+            words: &Words,
+            today: Day,
+            settings: &Settings,
+            day_stats: &DayStatistics,
+        ) {
+            self.update_lists(words, today, settings, day_stats);
+            if let Some(to_type_today) = &mut self.to_type_today {
+                to_type_today.all_words.retain(|x| words.contains(x));
+                to_type_today.current_batch.retain(|x| words.contains(x));
+            }
+            match &self.current {
+                LearnWords::None | LearnWords::Choose { .. } => {
+                    self.pick_current_type(words, today, settings);
                 }
-
-                if let Some(to_type_today) = &mut self.to_type_today {
-                    if to_type_today.current_batch.is_empty() {
-                        let (hint_words, guess_words): (Vec<_>, Vec<_>) = to_type_today
-                            .all_words
-                            .iter()
-                            .cloned()
-                            .partition(|x| words.has_hint(x, type_count));
-
-                        if hint_words.is_empty() {
-                            to_type_today.current_batch = guess_words;
+                LearnWords::Typing {
+                    word,
+                    correct_answer,
+                    ..
+                } => {
+                    let changed = !words.contains(word)
+                        || words.is_learned(word)
+                        || words.get_word_to_learn(word, today, settings, self.cram)
+                            != *correct_answer;
+                    if changed {
+                        let learnable = words.contains(word)
+                            && !words.is_learned(word)
+                            && (self.cram || words.can_learn_today(word, today, settings));
+                        let typing = if learnable {
+                            typing_state(word.clone(), words, today, settings, self.cram)
                         } else {
-                            to_type_today.current_batch = hint_words;
+                            None
+                        };
+                        match typing {
+                            Some(typing) => self.current = typing,
+                            None => self.pick_current_type(words, today, settings),
                         }
-
-                        to_type_today.current_batch.shuffle(rng);
                     }
-
-                    let word = to_type_today.current_batch.remove(0);
-                    if !words.is_learned(&word) {
-                        let max_types = words.max_attempts_remains(&word, today, type_count);
-                        let result = words.get_word_to_learn(&word, today, type_count);
-                        let words_to_type: Vec<String> = (0..result.words_to_type.len())
-                            .map(|_| String::new())
-                            .collect();
-                        let words_to_guess: Vec<String> = (0..result.words_to_guess.len())
-                            .map(|_| String::new())
-                            .collect();
-                        if words_to_type.is_empty() && words_to_guess.is_empty() {
-                            to_type_today.all_words.retain(|x| *x != word);
-                            to_type_today.current_batch.retain(|x| *x != word);
-                        } else {
-                            self.current = LearnWords::Typing {
-                                word,
-                                word_by_hint: (!words_to_type.is_empty()).then(String::new),
-                                correct_answer: result,
-                                words_to_type,
-                                max_types,
-                                words_to_guess,
-                                gain_focus: true,
-                            };
-                            return;
-                        }
-                    } else {
-                        to_type_today.all_words.retain(|x| *x != word);
-                        to_type_today.current_batch.retain(|x| *x != word);
+                }
+                LearnWords::Checked { word, .. } => {
+                    if !words.contains(word) {
+                        self.pick_current_type(words, today, settings);
                     }
-                } else {
-                    self.cancel_learning();
-                    return;
                 }
             }
         }
 
-        fn update(&mut self, words: &Words, today: Day, type_count: &[LearnType], rng: &mut Rand) {
-            let (repeat, new) = words.get_words_to_learn_today(today, type_count);
+        fn update_lists(
+            &mut self,
+            words: &Words,
+            today: Day,
+            settings: &Settings,
+            day_stats: &DayStatistics,
+        ) {
+            self.available_tags = words.all_tags();
+            self.tag_filter.retain(|x| self.available_tags.contains(x));
+            let (repeat, new) = words.get_words_to_learn_today(
+                today,
+                settings,
+                settings.review_limit_left(day_stats),
+                &self.tag_filter,
+                self.maturity_filter,
+            );
 
             self.to_type_repeat.clear();
             for i in repeat {
-                let overdue = words.max_overdue_days(&i, today, type_count);
+                let overdue = words.max_overdue_days(&i, today, settings);
                 self.to_type_repeat.push((i, overdue));
             }
             self.to_type_repeat.sort_by_key(|x| std::cmp::Reverse(x.1));
 
             self.to_type_new.clear();
             for i in new {
-                let overdue = words.max_overdue_days(&i, today, type_count);
+                let overdue = words.max_overdue_days(&i, today, settings);
                 self.to_type_new.push((i, overdue));
             }
             self.to_type_new.sort_by_key(|x| std::cmp::Reverse(x.1));
-
-            self.pick_current_type(words, today, type_count, rng);
         }
 
         #[allow(clippy::too_many_arguments)]
@@ -3298,11 +8202,24 @@ mod gui {
             words: &mut Words,
             today: Day,
             day_stats: &mut DayStatistics,
+            working_time: f64,
             settings: &Settings,
             save: &mut bool,
-            rng: &mut Rand,
+            tex_allocator: &mut dyn epi::TextureAllocator,
         ) {
             let mut cancel = false;
+            let mut undo = false;
+            let mut filter_changed = false;
+            let cram = self.cram;
+            if settings.skip_choose
+                && !self.cancelled
+                && matches!(self.current, LearnWords::Choose { .. })
+            {
+                self.choose_words(usize::MAX, usize::MAX, words, today, settings, day_stats);
+            }
+            let mut choose = None;
+            let session_words = self.session_words;
+            let session_attempts = self.session_attempts(day_stats);
             egui::Window::new("Learn words")
                 .fixed_size((300., 0.))
                 .collapsible(false)
@@ -3310,6 +8227,24 @@ mod gui {
                 .show(ctx, |ui| match &mut self.current {
                     LearnWords::None => {
                         ui.label("🎉🎉🎉 Everything is learned for today! 🎉🎉🎉");
+                        ui.separator();
+                        let attempts = day_stats.attempts.right + day_stats.attempts.wrong;
+                        ui.label(format!("Attempts today: {}", attempts));
+                        if attempts != 0 {
+                            ui.label(format!(
+                                "Accuracy: {:.1}%",
+                                day_stats.attempts.right as f64 / attempts as f64 * 100.
+                            ));
+                        }
+                        ui.label(format!(
+                            "New words added: {}",
+                            day_stats.new_unknown_words_count
+                        ));
+                        ui.label(format!("Working time: {}", print_time(working_time)));
+                        ui.label(format!(
+                            "Level-ups in this session: {}",
+                            self.session_level_ups
+                        ));
                     }
                     LearnWords::Choose {
                         all_repeat,
@@ -3318,6 +8253,44 @@ mod gui {
                         n_new,
                     } => {
                         ui.label("Choose words to work with now.");
+                        if !self.available_tags.is_empty() {
+                            ui.horizontal_wrapped(|ui| {
+                                ui.label("Tags:");
+                                for tag in &self.available_tags {
+                                    let selected = self.tag_filter.contains(tag);
+                                    if ui.selectable_label(selected, tag).clicked() {
+                                        if selected {
+                                            self.tag_filter.remove(tag);
+                                        } else {
+                                            self.tag_filter.insert(tag.clone());
+                                        }
+                                        filter_changed = true;
+                                    }
+                                }
+                            })
+                            .response
+                            .on_hover_text("Learn only words with selected tags. If nothing is selected, all words are learned");
+                        }
+                        ui.horizontal(|ui| {
+                            ui.label("Interval:");
+                            for (maturity, name) in [
+                                (None, "All"),
+                                (Some(Maturity::Young), "Young"),
+                                (Some(Maturity::Mature), "Mature"),
+                            ] {
+                                if ui
+                                    .selectable_value(&mut self.maturity_filter, maturity, name)
+                                    .changed()
+                                {
+                                    filter_changed = true;
+                                }
+                            }
+                        })
+                        .response
+                        .on_hover_text(format!(
+                            "Mature words are repeated with an interval of {} days or more",
+                            MATURE_INTERVAL_DAYS
+                        ));
                         ui.horizontal(|ui| {
                             ui.label("Old words to repeat: ");
                             ui.add(
@@ -3336,55 +8309,28 @@ mod gui {
                             );
                             ui.label(format!("/{}", all_new))
                         });
-                        if ui.button("Choose").clicked() {
-                            let to_type_repeat = &mut self.to_type_repeat;
-                            let to_type_new = &mut self.to_type_new;
-
-                            self.to_type_today = Some({
-                                let mut result = BTreeSet::new();
-
-                                while (n_repeat == all_repeat || result.len() < *n_repeat)
-                                    && !to_type_repeat.is_empty()
-                                {
-                                    let first = to_type_repeat[0].clone();
-                                    select_with_translations(
-                                        &first.0,
-                                        words,
-                                        today,
-                                        &settings.type_count,
-                                        |word| {
-                                            to_type_repeat.retain(|x| x.0 != word);
-                                            result.insert(word.to_string());
-                                        },
-                                    );
-                                }
-
-                                *n_repeat = result.len();
-
-                                while (n_new == all_new || result.len() < *n_repeat + *n_new)
-                                    && !to_type_new.is_empty()
-                                {
-                                    let first = to_type_new[0].clone();
-                                    select_with_translations(
-                                        &first.0,
-                                        words,
-                                        today,
-                                        &settings.type_count,
-                                        |word| {
-                                            to_type_new.retain(|x| x.0 != word);
-                                            result.insert(word.to_string());
-                                        },
-                                    );
-                                }
-
-                                ToTypeToday {
-                                    all_words: result.into_iter().collect(),
-                                    current_batch: Vec::new(),
-                                }
-                            });
-
-                            self.pick_current_type(words, today, &settings.type_count, rng);
-                        }
+                        // Enter при вводе числа вручную только подтверждает число
+                        let enter_pressed =
+                            ui.input().key_pressed(Key::Enter) && ui.memory().focus().is_none();
+                        ui.horizontal(|ui| {
+                            if ui.button("Choose").on_hover_text("Enter").clicked()
+                                || enter_pressed
+                            {
+                                // Если выбраны все слова, то берутся и переводы, добавленные вместе с ними
+                                let all = |n, all| if n == all { usize::MAX } else { n };
+                                choose = Some((
+                                    all(*n_repeat, *all_repeat),
+                                    all(*n_new, *all_new),
+                                ));
+                            }
+                            if ui
+                                .button("All due words")
+                                .on_hover_text("Learn all old and new words at once")
+                                .clicked()
+                            {
+                                choose = Some((usize::MAX, usize::MAX));
+                            }
+                        });
                     }
                     LearnWords::Typing {
                         word,
@@ -3394,6 +8340,8 @@ mod gui {
                         words_to_guess,
                         gain_focus,
                         max_types,
+                        started,
+                        flipped,
                     } => {
                         let len = self.to_type_today.as_ref().unwrap().all_words.len();
                         ui.with_layout(Layout::right_to_left(), |ui| {
@@ -3404,7 +8352,11 @@ mod gui {
                                 ui.label(format!("Words remains: {}.", len));
                             });
                         });
+                        session_progress_ui(ui, session_words, len, session_attempts);
                         ui.label(format!("This word attempts remains: {}.", max_types));
+                        if cram {
+                            ui.label("Cram mode: the schedule of words is not changed.");
+                        }
                         ui.separator();
 
                         let mut data = InputFieldData::new(settings, &mut *gain_focus);
@@ -3414,11 +8366,22 @@ mod gui {
                             InputField::Hint.ui(ui, &mut data, word_by_hint, word, settings);
                             ui.separator();
                         } else {
-                            ui.add(Label::new(&word).heading().strong());
+                            let prompt = if *flipped {
+                                &correct_answer.words_to_guess[0]
+                            } else {
+                                &*word
+                            };
+                            ui.horizontal(|ui| {
+                                ui.add(Label::new(prompt).heading().strong());
+                                speak_button(ui, prompt, settings);
+                            });
                         }
 
-                        for i in &mut correct_answer.known_words {
-                            ui.add_enabled(false, egui::TextEdit::singleline(i));
+                        // Известные переводы подсказали бы перевёрнутое слово
+                        if !*flipped {
+                            for i in &mut correct_answer.known_words {
+                                ui.add_enabled(false, egui::TextEdit::singleline(i));
+                            }
                         }
                         for (hint, i) in correct_answer
                             .words_to_type
@@ -3427,63 +8390,144 @@ mod gui {
                         {
                             InputField::Hint.ui(ui, &mut data, i, hint, settings);
                         }
-                        for (i, correct) in words_to_guess
+                        for (i, translation) in words_to_guess
                             .iter_mut()
                             .zip(correct_answer.words_to_guess.iter())
                         {
-                            InputField::Input.ui(ui, &mut data, i, correct, settings);
+                            let correct = if *flipped { &*word } else { translation };
+                            let hint = if settings.progressive_hints {
+                                progressive_hint(correct, words.wrong_attempts(word, translation))
+                            } else {
+                                String::new()
+                            };
+                            InputField::Input(&hint).ui(ui, &mut data, i, correct, settings);
                         }
 
-                        if input_field_button(ui, "Check", &mut data) {
-                            // Register just typed words
-                            for answer in &correct_answer.words_to_type {
-                                words.register_attempt(
-                                    word,
-                                    answer,
-                                    true,
-                                    today,
-                                    day_stats,
-                                    &settings.type_count,
-                                );
+                        let mut check = input_field_button(ui, "Check", &mut data);
+                        if let Some(limit) = settings.card_time_limit {
+                            let remains = limit - (now() - *started);
+                            if remains > 0. {
+                                ui.label(format!("Time remains: {:.0} s", remains.ceil()));
+                                ui.ctx().request_repaint();
+                            } else {
+                                // Время вышло, введённое проверяется как при нажатии на Check
+                                check = true;
                             }
+                        }
+                        let dont_know = ui
+                            .button("I don't know")
+                            .on_hover_text("Count all answers as wrong and show them")
+                            .clicked();
+
+                        if dont_know {
+                            let undo = Box::new(CheckUndo::new(
+                                words,
+                                word,
+                                day_stats,
+                                self.session_level_ups,
+                                word_by_hint,
+                                correct_answer,
+                                words_to_type,
+                                words_to_guess,
+                            ));
+                            let review = !cram && !words.is_new(word);
+
+                            // Все ответы считаются неправильными и будут учтены при нажатии на Next
+                            let result: Vec<TypedWord> = correct_answer
+                                .words_to_type
+                                .iter()
+                                .zip(words_to_type.iter())
+                                .chain(
+                                    correct_answer
+                                        .words_to_guess
+                                        .iter()
+                                        .zip(words_to_guess.iter()),
+                                )
+                                .map(|(translation, typed)| TypedWord {
+                                    correct: false,
+                                    typo: false,
+                                    translation: if *flipped {
+                                        word.clone()
+                                    } else {
+                                        translation.clone()
+                                    },
+                                    typed: typed.clone(),
+                                })
+                                .collect();
 
-                            let mut result = Vec::new();
-                            let mut answers = correct_answer.words_to_guess.clone();
-                            let mut corrects = Vec::new();
-                            for typed in &*words_to_guess {
-                                if let Some(position) = answers.iter().position(|x| x == typed) {
-                                    corrects.push(answers.remove(position));
-                                }
-                            }
+                            self.current = LearnWords::Checked {
+                                word: word.clone(),
+                                known_words: correct_answer.known_words.clone(),
+                                typed: Vec::new(),
+                                to_repeat: (0..result.len()).map(|_| String::new()).collect(),
+                                result,
+                                max_types: *max_types,
+                                gain_focus: true,
+                                undo,
+                                review,
+                                flipped: flipped.then(|| correct_answer.words_to_guess[0].clone()),
+                            };
+                        } else if check {
+                            let undo = Box::new(CheckUndo::new(
+                                words,
+                                word,
+                                day_stats,
+                                self.session_level_ups,
+                                word_by_hint,
+                                correct_answer,
+                                words_to_type,
+                                words_to_guess,
+                            ));
+                            let review = !cram && !words.is_new(word);
 
-                            for typed in &*words_to_guess {
-                                let (answer, correct) = if let Some(position) =
-                                    corrects.iter().position(|x| x == typed)
-                                {
-                                    (corrects.remove(position), true)
+                            // Register just typed words
+                            for answer in &correct_answer.words_to_type {
+                                if cram {
+                                    words.register_cram_attempt(word, answer, true, day_stats);
                                 } else {
-                                    (answers.remove(0), false)
-                                };
-
-                                result.push(TypedWord {
-                                    correct,
-                                    translation: answer,
-                                    typed: typed.clone(),
-                                });
+                                    if words.register_attempt(
+                                        word, answer, true, today, day_stats, settings,
+                                    ) {
+                                        self.session_level_ups += 1;
+                                    }
+                                }
                             }
 
+                            let answers = if *flipped {
+                                vec![word.clone()]
+                            } else {
+                                correct_answer.words_to_guess.clone()
+                            };
+                            let mut result = grade_guesses(&answers, words_to_guess, settings);
+
                             if result.is_empty() {
                                 for typed_word in result.iter_mut() {
-                                    words.register_attempt(
-                                        word,
-                                        &typed_word.translation,
-                                        typed_word.correct,
-                                        today,
-                                        day_stats,
-                                        &settings.type_count,
-                                    );
+                                    if cram {
+                                        words.register_cram_attempt(
+                                            word,
+                                            &typed_word.translation,
+                                            typed_word.correct,
+                                            day_stats,
+                                        );
+                                    } else {
+                                        if words.register_attempt(
+                                            word,
+                                            &typed_word.translation,
+                                            typed_word.correct,
+                                            today,
+                                            day_stats,
+                                            settings,
+                                        ) {
+                                            self.session_level_ups += 1;
+                                        }
+                                    }
                                 }
-                                self.pick_current_type(words, today, &settings.type_count, rng);
+                                if review
+                                    && !words.can_learn_today(word, today, settings)
+                                {
+                                    day_stats.reviewed_words_count += 1;
+                                }
+                                self.pick_current_type(words, today, settings);
                                 *save = true;
                             } else {
                                 self.current = LearnWords::Checked {
@@ -3494,6 +8538,10 @@ mod gui {
                                     result,
                                     max_types: *max_types,
                                     gain_focus: true,
+                                    undo,
+                                    review,
+                                    flipped: flipped
+                                        .then(|| correct_answer.words_to_guess[0].clone()),
                                 };
                             }
                         }
@@ -3506,19 +8554,40 @@ mod gui {
                         to_repeat,
                         max_types,
                         gain_focus,
+                        review,
+                        flipped,
+                        ..
                     } => {
                         let len = self.to_type_today.as_ref().unwrap().all_words.len();
+                        // Enter, когда ни одно поле не в фокусе, тоже переходит к следующему слову
+                        let enter_pressed =
+                            ui.input().key_pressed(Key::Enter) && ui.memory().focus().is_none();
                         ui.with_layout(Layout::right_to_left(), |ui| {
                             if ui.button("❌").clicked() {
                                 cancel = true;
                             }
+                            if ui
+                                .button("Undo last")
+                                .on_hover_text("Return to typing this word")
+                                .clicked()
+                            {
+                                undo = true;
+                            }
                             ui.with_layout(Layout::left_to_right(), |ui| {
                                 ui.label(format!("Words remains: {}.", len));
                             });
                         });
+                        session_progress_ui(ui, session_words, len, session_attempts);
                         ui.label(format!("This word attempts remains: {}.", max_types));
+                        if cram {
+                            ui.label("Cram mode: the schedule of words is not changed.");
+                        }
                         ui.separator();
-                        ui.add(Label::new(&word).heading().strong());
+                        let prompt = flipped.as_ref().unwrap_or(word);
+                        ui.horizontal(|ui| {
+                            ui.add(Label::new(prompt).heading().strong());
+                            speak_button(ui, prompt, settings);
+                        });
 
                         let mut data = InputFieldData::new(settings, &mut *gain_focus);
 
@@ -3531,13 +8600,11 @@ mod gui {
                                 ui,
                                 |ui| {
                                     ui.add_enabled(false, egui::TextEdit::singleline(i));
-                                },
-                                settings,
-                            );
+                                });
                         }
 
                         for word in result.iter_mut() {
-                            InputField::Checked(&mut word.correct).ui(
+                            InputField::Checked(&mut word.correct, word.typo).ui(
                                 ui,
                                 &mut data,
                                 &mut word.typed,
@@ -3546,6 +8613,34 @@ mod gui {
                             );
                         }
 
+                        let images = &mut self.images;
+                        for status in words.get(word).into_iter().flatten() {
+                            if let WordStatus::ToLearn {
+                                example,
+                                image_url,
+                                note,
+                                ..
+                            }
+                            | WordStatus::Learned {
+                                example,
+                                image_url,
+                                note,
+                                ..
+                            } = status
+                            {
+                                if let Some(example) = example {
+                                    ui.add(Label::new(example).italics().wrap(true));
+                                }
+                                if let Some(note) = note {
+                                    ui.add(Label::new(format!("Note: {}", note)).wrap(true));
+                                }
+                                if let Some(image_url) = image_url {
+                                    images.ui(ui, image_url, tex_allocator, settings);
+                                }
+                            }
+                        }
+                        group_members_ui(ui, words, word);
+
                         if result.iter().any(|x| !x.correct) {
                             ui.separator();
                             ui.label("Correction of mistakes:");
@@ -3563,33 +8658,201 @@ mod gui {
                             }
                         }
 
-                        if input_field_button(ui, "Next", &mut data) {
+                        let next_clicked = input_field_button(ui, "Next", &mut data);
+                        if next_clicked || (enter_pressed && data.next_enabled) {
                             for typed_word in result.iter_mut() {
-                                words.register_attempt(
-                                    word,
-                                    &typed_word.translation,
-                                    typed_word.correct,
-                                    today,
-                                    day_stats,
-                                    &settings.type_count,
-                                );
+                                // В перевёрнутой карточке вводилось само слово, а попытка засчитывается показанному переводу
+                                let translation =
+                                    flipped.as_ref().unwrap_or(&typed_word.translation);
+                                if cram {
+                                    words.register_cram_attempt(
+                                        word,
+                                        translation,
+                                        typed_word.correct,
+                                        day_stats,
+                                    );
+                                } else {
+                                    if words.register_attempt(
+                                        word,
+                                        translation,
+                                        typed_word.correct,
+                                        today,
+                                        day_stats,
+                                        settings,
+                                    ) {
+                                        self.session_level_ups += 1;
+                                    }
+                                }
+                            }
+                            if *review && !words.can_learn_today(word, today, settings)
+                            {
+                                day_stats.reviewed_words_count += 1;
                             }
-                            self.pick_current_type(words, today, &settings.type_count, rng);
+                            self.pick_current_type(words, today, settings);
                             *save = true;
                         }
                     }
                 });
+            if undo {
+                if let LearnWords::Checked {
+                    word,
+                    max_types,
+                    undo,
+                    flipped,
+                    ..
+                } = std::mem::replace(&mut self.current, LearnWords::None)
+                {
+                    let undo = *undo;
+                    if let Some(statuses) = words.0.get_mut(&word) {
+                        *statuses = undo.statuses;
+                    }
+                    day_stats.attempts = undo.attempts;
+                    day_stats.words_leveled.truncate(undo.words_leveled);
+                    day_stats.words_learned_today = undo.words_learned_today;
+                    day_stats.reviewed_words_count = undo.reviewed_words_count;
+                    self.session_level_ups = undo.session_level_ups;
+                    self.current = LearnWords::Typing {
+                        word,
+                        word_by_hint: undo.word_by_hint,
+                        correct_answer: undo.correct_answer,
+                        words_to_type: undo.words_to_type,
+                        words_to_guess: undo.words_to_guess,
+                        max_types,
+                        gain_focus: true,
+                        started: now(),
+                        flipped: flipped.is_some(),
+                    };
+                    *save = true;
+                }
+            }
+            if let Some((n_repeat, n_new)) = choose {
+                self.choose_words(n_repeat, n_new, words, today, settings, day_stats);
+            }
             if cancel {
-                self.update(words, today, &settings.type_count, rng);
+                self.update(words, today, settings, day_stats);
                 self.cancel_learning();
+                self.cancelled = true;
+            }
+            if filter_changed {
+                self.update(words, today, settings, day_stats);
+            }
+        }
+    }
+
+    /// Загруженные картинки к словам, чтобы не загружать их на каждом кадре
+    #[derive(Default)]
+    struct WordImages {
+        images: BTreeMap<String, Result<(TextureId, Vec2), String>>,
+        /// Картинки по ссылкам, которые ещё скачиваются
+        downloads: BTreeMap<String, Download<Vec<u8>>>,
+    }
+
+    impl WordImages {
+        fn ui(
+            &mut self,
+            ui: &mut Ui,
+            url: &str,
+            tex_allocator: &mut dyn epi::TextureAllocator,
+            settings: &Settings,
+        ) {
+            // В браузере картинки не декодируются, поэтому ссылка просто открывается
+            if cfg!(target_arch = "wasm32") {
+                ui.hyperlink_to("🖼 Open image", url)
+                    .on_hover_text("Images are shown only in the desktop version");
+                return;
+            }
+
+            if !self.images.contains_key(url) {
+                let image = if url.starts_with("http://") || url.starts_with("https://") {
+                    let download = self
+                        .downloads
+                        .entry(url.to_owned())
+                        .or_insert_with(|| fetch_bytes(url));
+                    let finished = download.lock().unwrap().take();
+                    match finished {
+                        Some(result) => {
+                            self.downloads.remove(url);
+                            result.and_then(|bytes| load_image_from_memory(&bytes, tex_allocator))
+                        }
+                        None => {
+                            ui.label("Downloading image...");
+                            ui.ctx().request_repaint();
+                            return;
+                        }
+                    }
+                } else {
+                    load_image(url, tex_allocator)
+                };
+                self.images.insert(url.to_owned(), image);
+            }
+
+            match &self.images[url] {
+                Ok((texture, size)) => {
+                    let scale = (ui.available_width() / size.x).min(1.);
+                    ui.image(*texture, *size * scale);
+                }
+                Err(error) => {
+                    ui.colored_label(
+                        settings.color_error(),
+                        format!("Can't load image: {}", error),
+                    );
+                }
             }
         }
     }
 
+    #[cfg(not(target_arch = "wasm32"))]
+    fn load_image(
+        path: &str,
+        tex_allocator: &mut dyn epi::TextureAllocator,
+    ) -> Result<(TextureId, Vec2), String> {
+        let image = image::open(path).map_err(|e| e.to_string())?;
+        Ok(image_texture(image, tex_allocator))
+    }
+
+    /// Картинка из скачанного файла, формат определяется по содержимому
+    #[cfg(not(target_arch = "wasm32"))]
+    fn load_image_from_memory(
+        bytes: &[u8],
+        tex_allocator: &mut dyn epi::TextureAllocator,
+    ) -> Result<(TextureId, Vec2), String> {
+        let image = image::load_from_memory(bytes).map_err(|e| e.to_string())?;
+        Ok(image_texture(image, tex_allocator))
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn image_texture(
+        image: image::DynamicImage,
+        tex_allocator: &mut dyn epi::TextureAllocator,
+    ) -> (TextureId, Vec2) {
+        let image = image.to_rgba8();
+        let size = (image.width() as usize, image.height() as usize);
+        let pixels: Vec<Color32> = image
+            .pixels()
+            .map(|p| Color32::from_rgba_unmultiplied(p[0], p[1], p[2], p[3]))
+            .collect();
+        let texture = tex_allocator.alloc_srgba_premultiplied(size, &pixels);
+        (texture, vec2(size.0 as f32, size.1 as f32))
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    fn load_image(_: &str, _: &mut dyn epi::TextureAllocator) -> Result<(TextureId, Vec2), String> {
+        Err("loading images is not supported in browser".to_owned())
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    fn load_image_from_memory(
+        _: &[u8],
+        _: &mut dyn epi::TextureAllocator,
+    ) -> Result<(TextureId, Vec2), String> {
+        Err("loading images is not supported in browser".to_owned())
+    }
+
     enum InputField<'a> {
         Hint,
-        Input,
-        Checked(&'a mut bool),
+        /// Поле для ввода с подсказкой внутри, которая может быть пустой
+        Input(&'a str),
+        Checked(&'a mut bool, bool),
     }
 
     struct FocusThing {
@@ -3636,8 +8899,14 @@ mod gui {
         }
 
         fn process_text(&self, input: &mut String, should_be: &str) {
-            if self.settings.use_keyboard_layout {
-                self.settings.keyboard_layout.change(should_be, input);
+            // Пустой правильный ответ бывает, если перевод случайно оказался пустым, по нему нельзя понять раскладку
+            if self.settings.use_keyboard_layout && !should_be.is_empty() {
+                let layout = &self.settings.keyboard_layout;
+                // Если первый символ ответа не из какой-либо раскладки, то ответ набирается в первой
+                let to = layout.profile_of(should_be).unwrap_or(0);
+                for from in (0..layout.profiles.len()).filter(|from| *from != to) {
+                    layout.change(from, to, input);
+                }
             }
         }
 
@@ -3684,6 +8953,52 @@ mod gui {
         }
     }
 
+    fn is_answer_correct(answer: &str, typed: &str, settings: &Settings) -> bool {
+        normalize_answer(answer, settings) == normalize_answer(typed, settings)
+    }
+
+    /// Ответ отличается от правильного не больше чем на допустимое число опечаток. Для коротких слов опечатки не допускаются.
+    fn is_answer_typo(answer: &str, typed: &str, settings: &Settings) -> bool {
+        let answer = normalize_answer(answer, settings);
+        answer.chars().count() > 2
+            && strsim::levenshtein(&answer, &normalize_answer(typed, settings))
+                <= settings.accept_typo_distance
+    }
+
+    /// Символы введённого ответа и совпадают ли они с правильным ответом. Совпадающими считаются общее начало и общий конец, остальное в середине отличается.
+    fn char_diff(typed: &str, correct: &str) -> Vec<(bool, char)> {
+        let typed: Vec<char> = typed.chars().collect();
+        let correct: Vec<char> = correct.chars().collect();
+        let prefix = typed
+            .iter()
+            .zip(correct.iter())
+            .take_while(|(a, b)| a == b)
+            .count();
+        let suffix = typed[prefix..]
+            .iter()
+            .rev()
+            .zip(correct[prefix..].iter().rev())
+            .take_while(|(a, b)| a == b)
+            .count();
+        typed
+            .iter()
+            .enumerate()
+            .map(|(i, c)| (i < prefix || i >= typed.len() - suffix, *c))
+            .collect()
+    }
+
+    /// Первые буквы ответа, по одной за каждый прошлый неправильный ответ. Весь ответ никогда не показывается
+    fn progressive_hint(answer: &str, wrong_attempts: u64) -> String {
+        let len = answer.chars().count().saturating_sub(1);
+        let shown = (wrong_attempts as usize).min(len);
+        if shown == 0 {
+            return String::new();
+        }
+        let mut result: String = answer.chars().take(shown).collect();
+        result.push('…');
+        result
+    }
+
     fn input_field_button(ui: &mut Ui, text: &str, data: &mut InputFieldData) -> bool {
         data.is_empty = true;
         let response = ui.add_enabled(data.next_enabled, Button::new(text));
@@ -3705,18 +9020,14 @@ mod gui {
             match self {
                 Hint => {
                     data.is_empty = input.is_empty();
-                    let response = if input == should_be {
-                        with_green_color(
-                            ui,
-                            |ui| {
-                                ui.add_enabled(
-                                    data.next_enabled,
-                                    egui::TextEdit::singleline(input)
-                                        .hint_text(format!(" {}", should_be)),
-                                )
-                            },
-                            settings,
-                        )
+                    let response = if is_answer_correct(should_be, input, settings) {
+                        with_green_color(ui, |ui| {
+                            ui.add_enabled(
+                                data.next_enabled,
+                                egui::TextEdit::singleline(input)
+                                    .hint_text(format!(" {}", should_be)),
+                            )
+                        })
                     } else {
                         ui.add_enabled(
                             data.next_enabled,
@@ -3725,16 +9036,18 @@ mod gui {
                     };
                     data.process_text(input, should_be);
                     data.process_focus(response, ui.input(), true);
-                    data.next_enabled &= input == should_be;
+                    data.next_enabled &= is_answer_correct(should_be, input, settings);
                 }
-                Input => {
+                Input(hint) => {
                     data.is_empty = input.is_empty();
-                    let response =
-                        ui.add_enabled(data.next_enabled, egui::TextEdit::singleline(input));
+                    let response = ui.add_enabled(
+                        data.next_enabled,
+                        egui::TextEdit::singleline(input).hint_text(*hint),
+                    );
                     data.process_text(input, should_be);
                     data.process_focus(response, ui.input(), true);
                 }
-                Checked(checked) => {
+                Checked(checked, typo) => {
                     ui.with_layout(Layout::right_to_left(), |ui| {
                         let response = ui.button("Invert");
                         if response.clicked() {
@@ -3742,76 +9055,180 @@ mod gui {
                         }
                         data.process_focus(response, ui.input(), false);
                         if **checked {
-                            ui.label(format!("✅ {}", should_be));
-                            with_green_color(
-                                ui,
-                                |ui| {
-                                    ui.add_enabled(false, egui::TextEdit::singleline(input));
-                                },
-                                settings,
-                            );
+                            if *typo {
+                                ui.label(format!("✅ (typo) {}", should_be));
+                            } else {
+                                ui.label(format!("✅ {}", should_be));
+                            }
+                            with_green_color(ui, |ui| {
+                                ui.add_enabled(false, egui::TextEdit::singleline(input));
+                            });
                         } else {
                             ui.label(format!("❌ {}", should_be));
-                            with_red_color(
-                                ui,
-                                |ui| {
-                                    ui.add_enabled(false, egui::TextEdit::singleline(input));
-                                },
-                                settings,
-                            );
+                            with_red_color(ui, |ui| {
+                                ui.add_enabled(false, egui::TextEdit::singleline(input));
+                            });
                         }
                     });
+                    if !**checked && !input.is_empty() {
+                        ui.horizontal_wrapped(|ui| {
+                            ui.spacing_mut().item_spacing.x = 0.;
+                            ui.label("Difference: ");
+                            for (same, c) in char_diff(input, should_be) {
+                                if same {
+                                    ui.monospace(c.to_string());
+                                } else {
+                                    ui.add(
+                                        Label::new(c.to_string())
+                                            .text_color(settings.color_error())
+                                            .monospace(),
+                                    );
+                                }
+                            }
+                        });
+                    }
                 }
             }
         }
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn word_to_add(
         ui: &mut Ui,
         word: &mut String,
         translations: &mut String,
         known_translations: &mut String,
+        direction: &mut LearnDirection,
+        direction_touched: &mut bool,
+        tags: &mut String,
+        new_limit_reached: bool,
     ) -> Option<(String, WordsToAdd)> {
         let mut action = None;
+        // Цифры работают только когда не вводится текст
+        let no_focus = ui.memory().focus().is_none();
+        let (know_pressed, trash_pressed, add_pressed) = {
+            let input = ui.input();
+            (
+                input.modifiers.alt && input.key_pressed(Key::K)
+                    || no_focus && input.key_pressed(Key::Num1),
+                input.modifiers.alt && input.key_pressed(Key::T)
+                    || no_focus && input.key_pressed(Key::Num2),
+                input.modifiers.command && input.key_pressed(Key::Enter),
+            )
+        };
+        let mut text_changed = false;
         ui.horizontal(|ui| {
             ui.label("Word:");
-            ui.text_edit_singleline(word);
+            text_changed |= ui
+                .add(TextEdit::singleline(word).hint_text("Word or phrase"))
+                .changed();
         });
         ui.separator();
         ui.horizontal(|ui| {
-            if ui.button("Know this word").clicked() {
-                action = Some((word.clone(), WordsToAdd::KnowPreviously));
+            if ui
+                .button("Know this word")
+                .on_hover_text("Alt+K or 1")
+                .clicked()
+                || know_pressed
+            {
+                action = Some((normalize_phrase(word), WordsToAdd::KnowPreviously));
             }
-            if ui.button("Trash word").clicked() {
-                action = Some((word.clone(), WordsToAdd::TrashWord));
+            if ui
+                .button("Trash word")
+                .on_hover_text("Alt+T or 2")
+                .clicked()
+                || trash_pressed
+            {
+                action = Some((normalize_phrase(word), WordsToAdd::TrashWord));
             }
         });
         ui.separator();
         ui.label("Translations:");
-        ui.add(TextEdit::multiline(translations).desired_rows(2));
+        text_changed |= ui
+            .add(TextEdit::multiline(translations).desired_rows(2))
+            .changed();
         ui.separator();
         ui.label("Known translations:");
         ui.add(TextEdit::multiline(known_translations).desired_rows(2));
-        if ui.button("Add these translations").clicked() {
+        let word_script = detect_script(word);
+        let translations_script = detect_script(translations);
+        if text_changed && !*direction_touched {
+            *direction = LearnDirection::suggest(word_script, translations_script);
+        }
+        ui.horizontal(|ui| {
+            let mut clicked = ui
+                .radio_value(direction, LearnDirection::Both, "Both")
+                .on_hover_text("Type translation by word and word by translation")
+                .clicked();
+            clicked |= ui
+                .radio_value(
+                    direction,
+                    LearnDirection::Forward,
+                    format!(
+                        "Forward ({} → {})",
+                        word_script.name(),
+                        translations_script.name()
+                    ),
+                )
+                .on_hover_text("Only type translation by word")
+                .clicked();
+            clicked |= ui
+                .radio_value(
+                    direction,
+                    LearnDirection::Backward,
+                    format!(
+                        "Backward ({} → {})",
+                        translations_script.name(),
+                        word_script.name()
+                    ),
+                )
+                .on_hover_text("Only type word by translation")
+                .clicked();
+            *direction_touched |= clicked;
+        });
+        ui.horizontal(|ui| {
+            ui.label("Tags:");
+            ui.add(TextEdit::singleline(tags).hint_text("Comma separated"));
+        });
+        if new_limit_reached {
+            ui.label("Daily limit of new words is reached, these translations will be learned on the next days.");
+        }
+        if ui
+            .add(Button::new("Add these translations"))
+            .on_hover_text("Ctrl+Enter")
+            .clicked()
+            || add_pressed
+        {
             action = Some((
-                word.clone(),
+                normalize_phrase(word),
                 WordsToAdd::ToLearn {
                     learned: known_translations
                         .split('\n')
-                        .map(|x| x.to_string())
+                        .map(normalize_phrase)
                         .filter(|x| !x.is_empty())
                         .collect(),
                     translations: translations
                         .split('\n')
-                        .map(|x| x.to_string())
+                        .map(normalize_phrase)
                         .filter(|x| !x.is_empty())
                         .collect(),
+                    direction: *direction,
+                    tags: parse_tags(tags),
                 },
             ));
         }
         action
     }
 
+    /// Кнопка, которая произносит слово. Не показывается, если синтез речи недоступен.
+    fn speak_button(ui: &mut Ui, text: &str, settings: &Settings) {
+        if let Some(synth) = speech_synth(settings) {
+            if ui.button("🔊").on_hover_text("Pronounce").clicked() {
+                synth.speak(text, settings.speech_lang(text));
+            }
+        }
+    }
+
     fn with_color<Res>(
         ui: &mut Ui,
         color1: Color32,
@@ -3829,32 +9246,85 @@ mod gui {
         result
     }
 
-    fn with_green_color<Res>(
+    fn session_progress_ui(
         ui: &mut Ui,
-        f: impl FnOnce(&mut Ui) -> Res,
-        settings: &Settings,
-    ) -> Res {
-        with_color(
-            ui,
-            settings.color_green_field_1(),
-            settings.color_green_field_2(),
-            settings.color_green_field_3(),
-            f,
-        )
+        session_words: usize,
+        remains: usize,
+        attempts: TypingStats,
+    ) {
+        if session_words == 0 {
+            return;
+        }
+        let done = session_words.saturating_sub(remains);
+        let progress = done as f32 / session_words as f32;
+        ui.add(ProgressBar::new(progress).text(format!("{} / {}", done, session_words)));
+        let all = attempts.right + attempts.wrong;
+        if all != 0 {
+            ui.label(format!(
+                "Session accuracy: {:.1}% ({} of {})",
+                attempts.right as f64 / all as f64 * 100.,
+                attempts.right,
+                all
+            ));
+        }
     }
 
-    fn with_red_color<Res>(
-        ui: &mut Ui,
-        f: impl FnOnce(&mut Ui) -> Res,
-        settings: &Settings,
-    ) -> Res {
-        with_color(
-            ui,
-            settings.color_red_field_1(),
-            settings.color_red_field_2(),
-            settings.color_red_field_3(),
-            f,
-        )
+    /// Цвета берутся из текущей темы, а не из настроек, чтобы поля читались при любой теме
+    fn with_green_color<Res>(ui: &mut Ui, f: impl FnOnce(&mut Ui) -> Res) -> Res {
+        if ui.visuals().dark_mode {
+            with_color(
+                ui,
+                Color32::GREEN,
+                Color32::from_rgb_additive(0, 128, 0),
+                Color32::from_rgb_additive(128, 255, 128),
+                f,
+            )
+        } else {
+            with_color(
+                ui,
+                Color32::from_rgba_unmultiplied(0, 255, 0, 255),
+                Color32::from_rgba_unmultiplied(0, 195, 63, 201),
+                Color32::from_rgba_unmultiplied(47, 198, 0, 191),
+                f,
+            )
+        }
+    }
+
+    fn with_red_color<Res>(ui: &mut Ui, f: impl FnOnce(&mut Ui) -> Res) -> Res {
+        if ui.visuals().dark_mode {
+            with_color(
+                ui,
+                Color32::RED,
+                Color32::from_rgb_additive(128, 0, 0),
+                Color32::from_rgb_additive(255, 128, 128),
+                f,
+            )
+        } else {
+            with_color(
+                ui,
+                Color32::from_rgba_unmultiplied(255, 0, 0, 255),
+                Color32::from_rgba_unmultiplied(224, 0, 0, 200),
+                Color32::from_rgba_unmultiplied(255, 128, 128, 255),
+                f,
+            )
+        }
+    }
+
+    /// Показывает остальные слова из групп форм этого слова
+    fn group_members_ui(ui: &mut Ui, words: &Words, word: &str) {
+        for group in words.groups_of(word) {
+            let members = words.group_members(group);
+            ui.horizontal_wrapped(|ui| {
+                ui.label(format!("Group '{}':", group));
+                for member in members {
+                    if member == word {
+                        ui.strong(member);
+                    } else {
+                        ui.label(member);
+                    }
+                }
+            });
+        }
     }
 
     fn word_status_show_ui(word: &WordStatus, ui: &mut Ui) {
@@ -3867,25 +9337,65 @@ mod gui {
                 last_learn,
                 current_level,
                 current_count,
+                ease,
                 stats,
+                ..
             } => {
                 ui.label(format!("To learn: '{}'", translation));
                 ui.label(format!("Attempts: +{}, -{}", stats.right, stats.wrong));
                 ui.label(format!("Last learned: {} day", last_learn.0));
                 ui.label(format!("Current level: {}", current_level));
-                ui.label(format!("Current correct writes: {}", current_count))
+                ui.label(format!("Current correct writes: {}", current_count));
+                ui.label(format!("Ease: {:.2}", ease))
             }
-            Learned { translation, stats } => {
+            Learned {
+                translation, stats, ..
+            } => {
                 ui.label(format!("Learned: '{}'", translation));
                 ui.label(format!("Attempts: +{}, -{}", stats.right, stats.wrong))
             }
+            Suspended {
+                translation,
+                saved_level,
+                stats,
+                ..
+            } => {
+                ui.label(format!("Suspended: '{}'", translation));
+                ui.label(format!("Attempts: +{}, -{}", stats.right, stats.wrong));
+                ui.label(format!("Saved level: {}", saved_level))
+            }
+        };
+        if let Some(note) = word.note() {
+            ui.add(Label::new(format!("Note: {}", note)).wrap(true));
+        }
+        history_ui(ui, word.history());
+    }
+
+    /// Последние попытки в виде точек: зелёная — правильно, красная — ошибка
+    fn history_ui(ui: &mut Ui, history: &[(Day, bool)]) {
+        if history.is_empty() {
+            return;
+        }
+        let (right, wrong) = if ui.visuals().dark_mode {
+            (Color32::GREEN, Color32::RED)
+        } else {
+            (Color32::from_rgb(0, 160, 0), Color32::from_rgb(200, 0, 0))
         };
+        ui.horizontal(|ui| {
+            ui.label("Recent attempts: ");
+            ui.spacing_mut().item_spacing.x = 1.;
+            for (day, correct) in history {
+                let color = if *correct { right } else { wrong };
+                ui.colored_label(color, "●")
+                    .on_hover_text(date_from_day(*day).format("%Y-%m-%d").to_string());
+            }
+        });
     }
 
     pub trait ComboBoxChoosable {
         fn variants() -> &'static [&'static str];
         fn get_number(&self) -> usize;
-        fn set_number(&mut self, number: usize);
+        fn set_number(&mut self, number: usize, today: Day);
     }
 
     impl ComboBoxChoosable for WordStatus {
@@ -3899,59 +9409,61 @@ mod gui {
                 TrashWord => 1,
                 ToLearn { .. } => 2,
                 Learned { .. } => 3,
+                // Приостановленное слово редактируется как изучаемое
+                Suspended { .. } => 2,
             }
         }
-        fn set_number(&mut self, number: usize) {
+        /// Новое изучаемое слово считается изученным в последний раз сегодня, иначе оно сразу стало бы просроченным
+        fn set_number(&mut self, number: usize, today: Day) {
             use WordStatus::*;
+            self.unsuspend();
+            let (translation, stats) = match self {
+                ToLearn {
+                    translation, stats, ..
+                }
+                | Learned {
+                    translation, stats, ..
+                } => (std::mem::take(translation), *stats),
+                _ => Default::default(),
+            };
+            let extras = self.take_extras();
             *self = match number {
                 0 => KnowPreviously,
                 1 => TrashWord,
-                2 => {
-                    if let Learned { translation, stats } = self {
-                        ToLearn {
-                            translation: translation.to_string(),
-                            stats: *stats,
-                            last_learn: Day(0),
-                            current_level: 0,
-                            current_count: 0,
-                        }
-                    } else {
-                        ToLearn {
-                            translation: String::new(),
-                            stats: TypingStats { right: 0, wrong: 0 },
-                            last_learn: Day(0),
-                            current_level: 0,
-                            current_count: 0,
-                        }
-                    }
-                }
-                3 => {
-                    if let ToLearn {
-                        translation, stats, ..
-                    } = self
-                    {
-                        Learned {
-                            translation: translation.to_string(),
-                            stats: *stats,
-                        }
-                    } else {
-                        Learned {
-                            translation: String::new(),
-                            stats: TypingStats { right: 0, wrong: 0 },
-                        }
-                    }
-                }
+                2 => WordStatus::new_to_learn(translation, today, stats, extras),
+                3 => WordStatus::new_learned(translation, stats, extras),
                 _ => unreachable!(),
             };
         }
     }
 
+    /// Поле для необязательного текста, пустая строка превращается в None
+    fn optional_text_ui(ui: &mut Ui, label: &str, value: &mut Option<String>, hint: &str) -> bool {
+        let mut text = value.clone().unwrap_or_default();
+        let changed = ui
+            .horizontal(|ui| {
+                ui.label(label);
+                ui.add(TextEdit::singleline(&mut text).hint_text(hint))
+                    .changed()
+            })
+            .inner;
+        if changed {
+            *value = if text.trim().is_empty() {
+                None
+            } else {
+                Some(text)
+            };
+        }
+        changed
+    }
+
     fn word_status_edit_ui(
         word: &mut WordStatus,
         ui: &mut Ui,
         rename: &mut Option<(String, String)>,
         is_delete: &mut bool,
         settings: &Settings,
+        today: Day,
     ) -> bool {
         use WordStatus::*;
 
@@ -3981,14 +9493,76 @@ mod gui {
         });
 
         if current_type != previous_type {
-            word.set_number(current_type);
+            word.set_number(current_type, today);
             changed = true;
         }
 
+        match word {
+            ToLearn { .. } => {
+                let response = ui
+                    .button("⏸ Suspend")
+                    .on_hover_text("Exclude this word from reviews, keeping its progress");
+                if response.clicked() {
+                    word.suspend();
+                    changed = true;
+                }
+            }
+            Suspended { .. } => {
+                ui.label("Reviews of this word are suspended.");
+                if ui.button("▶ Unsuspend").clicked() {
+                    word.unsuspend();
+                    changed = true;
+                }
+            }
+            _ => {}
+        }
+
+        if matches!(word, ToLearn { .. } | Learned { .. } | Suspended { .. }) {
+            ui.horizontal(|ui| {
+                if !matches!(word, Learned { .. }) && ui.button("Mark as learned now").clicked() {
+                    word.mark_learned();
+                    changed = true;
+                }
+                let response = ui
+                    .button("Reset progress")
+                    .on_hover_text("Learn this word again from the first level, starting today");
+                if response.clicked() {
+                    word.reset_progress(today);
+                    changed = true;
+                }
+            });
+        }
+
         if let ToLearn {
-            translation, stats, ..
+            translation,
+            stats,
+            tags,
+            example,
+            image_url,
+            group,
+            note,
+            ..
         }
-        | Learned { translation, stats } = word
+        | Learned {
+            translation,
+            stats,
+            tags,
+            example,
+            image_url,
+            group,
+            note,
+            ..
+        }
+        | Suspended {
+            translation,
+            stats,
+            tags,
+            example,
+            image_url,
+            group,
+            note,
+            ..
+        } = word
         {
             let previous = translation.clone();
 
@@ -3998,6 +9572,50 @@ mod gui {
                 *rename = Some((previous, translation.clone()));
             }
 
+            // Текст с темами хранится отдельно, иначе при разборе на каждом кадре нельзя было бы ввести запятую
+            let id = ui.id().with("tags").with(&*translation);
+            let mut text = ui
+                .memory()
+                .id_data_temp
+                .get_or_insert_with(id, || tags_to_string(tags))
+                .clone();
+            ui.horizontal(|ui| {
+                ui.label("Tags: ");
+                if ui
+                    .add(TextEdit::singleline(&mut text).hint_text("Comma separated"))
+                    .changed()
+                {
+                    *tags = parse_tags(&text);
+                    changed = true;
+                }
+            });
+            ui.memory().id_data_temp.insert(id, text);
+
+            if optional_text_ui(ui, "Example: ", example, "Sentence with this word") {
+                changed = true;
+            }
+            if optional_text_ui(ui, "Image: ", image_url, "File path or link") {
+                changed = true;
+            }
+            if optional_text_ui(ui, "Group: ", group, "Base form, e.g. 'go'") {
+                changed = true;
+            }
+            ui.label("Note: ");
+            let mut text = note.clone().unwrap_or_default();
+            let response = ui.add(
+                TextEdit::multiline(&mut text)
+                    .desired_rows(2)
+                    .hint_text("Mnemonic or usage note"),
+            );
+            if response.changed() {
+                *note = if text.trim().is_empty() {
+                    None
+                } else {
+                    Some(text)
+                };
+                changed = true;
+            }
+
             ui.horizontal(|ui| {
                 ui.label("Right attempts: ");
                 let response = ui.add(
@@ -4025,9 +9643,23 @@ mod gui {
             last_learn,
             current_level,
             current_count,
+            ease,
+            force_mode,
             ..
         } = word
         {
+            ui.horizontal(|ui| {
+                ui.label("Show word: ");
+                for (value, name) in [
+                    (None, "By schedule"),
+                    (Some(true), "Always"),
+                    (Some(false), "Never"),
+                ] {
+                    if ui.selectable_value(force_mode, value, name).changed() {
+                        changed = true;
+                    }
+                }
+            });
             ui.horizontal(|ui| {
                 ui.label("Last learn: ");
                 let response = ui.add(
@@ -4061,15 +9693,426 @@ mod gui {
                     changed = true;
                 }
             });
+            ui.horizontal(|ui| {
+                ui.label("Ease: ");
+                let response = ui.add(
+                    egui::DragValue::new(ease)
+                        .clamp_range(MIN_EASE..=5.0)
+                        .speed(0.01)
+                        .max_decimals(2),
+                );
+                if response.changed() {
+                    changed = true;
+                }
+            });
         }
         changed
     }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use crate::tests::{learned, stats_with_days, to_learn, words};
+
+        fn current_word(window: &LearnWordsWindow) -> Option<&str> {
+            match &window.current {
+                LearnWords::Typing { word, .. } => Some(word),
+                _ => None,
+            }
+        }
+
+        fn start_session(words: &Words, today: Day, settings: &Settings) -> LearnWordsWindow {
+            let day_stats = DayStatistics::default();
+            let mut window = LearnWordsWindow::with_seed(0, words, today, settings, &day_stats);
+            window.choose_words(usize::MAX, usize::MAX, words, today, settings, &day_stats);
+            window
+        }
+
+        fn two_words() -> Words {
+            words(vec![
+                ("cat", to_learn("кот", Day(100), 0)),
+                ("dog", to_learn("собака", Day(100), 0)),
+            ])
+        }
+
+        /// Порядок, в котором окно с заданным зерном показывает слова первой пачки
+        fn session_order(words: &Words, seed: u64) -> Vec<String> {
+            let settings = Settings::default();
+            let day_stats = DayStatistics::default();
+            let mut window =
+                LearnWordsWindow::with_seed(seed, words, Day(100), &settings, &day_stats);
+            window.choose_words(
+                usize::MAX,
+                usize::MAX,
+                words,
+                Day(100),
+                &settings,
+                &day_stats,
+            );
+            let mut result = vec![current_word(&window).unwrap().to_string()];
+            result.extend(
+                window
+                    .to_type_today
+                    .as_ref()
+                    .unwrap()
+                    .current_batch
+                    .iter()
+                    .cloned(),
+            );
+            result
+        }
+
+        #[test]
+        fn same_seed_gives_same_order() {
+            let words = words(
+                ["a", "b", "c", "d", "e", "f", "g", "h", "i", "j"]
+                    .iter()
+                    .map(|x| (*x, to_learn("перевод", Day(100), 0)))
+                    .collect(),
+            );
+            let order = session_order(&words, 1);
+            assert_eq!(order.len(), 10);
+            assert_eq!(session_order(&words, 1), order);
+            assert_ne!(session_order(&words, 2), order);
+        }
+
+        #[test]
+        fn word_removed_during_session_is_repicked() {
+            let settings = Settings::default();
+            let mut words = two_words();
+            let mut window = start_session(&words, Day(100), &settings);
+            let word = current_word(&window).unwrap().to_string();
+
+            words.remove_word(&word);
+            window.words_edited(&words, Day(100), &settings, &DayStatistics::default());
+            let other = if word == "cat" { "dog" } else { "cat" };
+            assert_eq!(current_word(&window), Some(other));
+        }
+
+        #[test]
+        fn word_learned_during_session_is_repicked() {
+            let settings = Settings::default();
+            let mut words = two_words();
+            let mut window = start_session(&words, Day(100), &settings);
+            let word = current_word(&window).unwrap().to_string();
+
+            words.set_status(&word, learned("перевод"));
+            window.words_edited(&words, Day(100), &settings, &DayStatistics::default());
+            assert_ne!(current_word(&window), Some(word.as_str()));
+            assert!(current_word(&window).is_some());
+        }
+
+        #[test]
+        fn translation_edited_during_session_is_updated() {
+            let settings = Settings::default();
+            let mut words = words(vec![("cat", to_learn("кот", Day(100), 0))]);
+            let mut window = start_session(&words, Day(100), &settings);
+            assert_eq!(current_word(&window), Some("cat"));
+
+            words.0.get_mut("cat").unwrap()[0] = to_learn("кошка", Day(100), 0);
+            window.words_edited(&words, Day(100), &settings, &DayStatistics::default());
+            match &window.current {
+                LearnWords::Typing {
+                    word,
+                    correct_answer,
+                    ..
+                } => {
+                    assert_eq!(word, "cat");
+                    assert_eq!(correct_answer.words_to_type, vec!["кошка"]);
+                }
+                _ => panic!("the edited word must still be typed"),
+            }
+        }
+
+        #[test]
+        fn shuffled_guesses_are_graded_by_value() {
+            let settings = Settings::default();
+            let translations = ["кот", "кошка", "котёнок", "котик"];
+            let words = words(
+                translations
+                    .iter()
+                    .map(|x| ("cat", to_learn(x, Day(100), 1)))
+                    .collect(),
+            );
+            let to_learn = words.get_word_to_learn("cat", Day(100), &settings, false);
+            let mut shuffled = to_learn.words_to_guess.clone();
+            shuffled.sort();
+            let mut expected: Vec<String> = translations.iter().map(|x| x.to_string()).collect();
+            expected.sort();
+            assert_eq!(shuffled, expected);
+
+            // В течение дня порядок не меняется
+            let again = words.get_word_to_learn("cat", Day(100), &settings, false);
+            assert_eq!(again.words_to_guess, to_learn.words_to_guess);
+
+            let typed: Vec<String> = translations.iter().map(|x| x.to_string()).collect();
+            let result = grade_guesses(&to_learn.words_to_guess, &typed, &settings);
+            assert!(result.iter().all(|x| x.correct && !x.typo));
+            for x in &result {
+                assert_eq!(x.translation, x.typed);
+            }
+        }
+
+        fn strings(x: &[&str]) -> Vec<String> {
+            x.iter().map(|x| x.to_string()).collect()
+        }
+
+        #[test]
+        fn duplicate_translations_are_accepted_as_many_times_as_they_occur() {
+            let settings = Settings::default();
+            let result = grade_guesses(
+                &strings(&["кот", "кот"]),
+                &strings(&["кот", "кот"]),
+                &settings,
+            );
+            assert!(result.iter().all(|x| x.correct && !x.typo));
+        }
+
+        #[test]
+        fn repeated_translation_is_wrong() {
+            let settings = Settings::default();
+            let result = grade_guesses(
+                &strings(&["кот", "кошка"]),
+                &strings(&["кот", "кот"]),
+                &settings,
+            );
+            assert!(result[0].correct);
+            assert_eq!(result[0].translation, "кот");
+            assert!(!result[1].correct);
+            assert_eq!(result[1].translation, "кошка");
+        }
+
+        #[test]
+        fn typo_does_not_take_correctly_typed_translation() {
+            let settings = Settings {
+                accept_typo_distance: 1,
+                ..Default::default()
+            };
+            let result = grade_guesses(
+                &strings(&["кошка", "кошки"]),
+                &strings(&["кошкы", "кошка"]),
+                &settings,
+            );
+            assert!(result[0].correct && result[0].typo);
+            assert_eq!(result[0].translation, "кошки");
+            assert!(result[1].correct && !result[1].typo);
+            assert_eq!(result[1].translation, "кошка");
+        }
+
+        #[test]
+        fn activity_window_of_empty_statistics() {
+            let window = GithubActivityWindow::new(&Statistics::default(), Day(100));
+            assert_eq!(window.min_day, Day(100));
+            assert_eq!(window.max_day, Day(100));
+            assert!(window.data_by_bucket.is_empty());
+            assert_eq!(window.get_normalized_value(Day(100)), None);
+            assert_eq!(window.get_value_text(Day(100)), None);
+        }
+
+        #[test]
+        fn single_day_activity_has_full_color() {
+            let mut window = GithubActivityWindow::new(&stats_with_days(&[100], &[]), Day(100));
+            for show in 0..3 {
+                window.show = show;
+                let value = window.get_normalized_value(Day(100)).unwrap();
+                assert_eq!(value, 1.0);
+            }
+        }
+
+        #[test]
+        fn activity_is_normalized_between_min_and_max() {
+            let mut stats = stats_with_days(&[100, 101, 102], &[]);
+            stats.by_day.get_mut(&Day(101)).unwrap().attempts.right = 2;
+            stats.by_day.get_mut(&Day(102)).unwrap().attempts.right = 3;
+            let window = GithubActivityWindow::new(&stats, Day(102));
+            assert_eq!(window.get_normalized_value(Day(100)), Some(0.0));
+            assert_eq!(window.get_normalized_value(Day(101)), Some(0.5));
+            assert_eq!(window.get_normalized_value(Day(102)), Some(1.0));
+        }
+
+        #[test]
+        fn learned_word_switched_to_learning_is_due_today() {
+            let settings = Settings::default();
+            let mut status = learned("кот");
+            status.set_number(2, Day(500));
+            assert_eq!(status.translation(), Some("кот"));
+            assert_eq!(status.next_due_date(&settings.type_count), Some(Day(500)));
+            assert!(status.can_learn_today(Day(500), &settings.type_count));
+            assert_eq!(status.overdue_days(Day(500), &settings.type_count), 0);
+        }
+
+        #[test]
+        fn known_word_switched_to_learning_is_due_today() {
+            let settings = Settings::default();
+            let mut status = WordStatus::KnowPreviously;
+            status.set_number(2, Day(500));
+            assert_eq!(status.next_due_date(&settings.type_count), Some(Day(500)));
+            assert_eq!(status.overdue_days(Day(500), &settings.type_count), 0);
+        }
+
+        #[test]
+        fn backward_words_are_not_offered_again() {
+            let settings = Settings::default();
+            let window = LoadTextWindow::new(TextFormat::Text);
+            let text = "cat dog cat";
+            let mut data = Words::default();
+
+            let (first, stats) =
+                window.filter_words(get_words(text, false, Default::default()), &data, &settings);
+            assert_eq!(stats.unknown_words, 2);
+            for (word, _) in first.words_with_context.0 {
+                let translation = format!("{} по-русски", word);
+                let skipped = data.add_word(
+                    word,
+                    WordsToAdd::ToLearn {
+                        learned: Vec::new(),
+                        translations: vec![translation],
+                        direction: LearnDirection::Backward,
+                        tags: BTreeSet::new(),
+                    },
+                    Day(100),
+                    &mut DayStatistics::default(),
+                    &settings,
+                );
+                assert_eq!(skipped, 0);
+            }
+            assert!(!data.contains("cat"));
+            assert!(data.has_translation("cat", "cat по-русски"));
+
+            let (second, stats) =
+                window.filter_words(get_words(text, false, Default::default()), &data, &settings);
+            assert!(second.words_with_context.0.is_empty());
+            assert_eq!(stats.unknown_words, 0);
+            assert_eq!(stats.filtered_learned, 2);
+        }
+
+        #[test]
+        fn backward_duplicate_is_skipped() {
+            let settings = Settings::default();
+            let mut data = Words::default();
+            let add = |data: &mut Words| {
+                data.add_word(
+                    "cat".to_string(),
+                    WordsToAdd::ToLearn {
+                        learned: Vec::new(),
+                        translations: vec!["кот".to_string()],
+                        direction: LearnDirection::Backward,
+                        tags: BTreeSet::new(),
+                    },
+                    Day(100),
+                    &mut DayStatistics::default(),
+                    &settings,
+                )
+            };
+            assert_eq!(add(&mut data), 0);
+            assert_eq!(add(&mut data), 1);
+            assert_eq!(data.get("кот").unwrap().len(), 1);
+        }
+
+        /// Запоминает размеры текстур вместо их загрузки в видеокарту
+        #[derive(Default)]
+        struct TestTextures(Vec<(usize, usize)>);
+
+        impl epi::TextureAllocator for TestTextures {
+            fn alloc_srgba_premultiplied(
+                &mut self,
+                size: (usize, usize),
+                _: &[Color32],
+            ) -> TextureId {
+                self.0.push(size);
+                TextureId::User(self.0.len() as u64)
+            }
+
+            fn free(&mut self, _: TextureId) {}
+        }
+
+        #[test]
+        fn downloaded_image_is_decoded() {
+            let mut png = Vec::new();
+            image::DynamicImage::new_rgba8(3, 2)
+                .write_to(&mut png, image::ImageOutputFormat::Png)
+                .unwrap();
+            let mut textures = TestTextures::default();
+            let (_, size) = load_image_from_memory(&png, &mut textures).unwrap();
+            assert_eq!(size, vec2(3., 2.));
+            assert_eq!(textures.0, vec![(3, 2)]);
+
+            assert!(load_image_from_memory(b"not an image", &mut textures).is_err());
+        }
+
+        #[test]
+        fn activity_color_endpoints_are_palette_stops() {
+            assert_eq!(
+                activity_color(0., ActivityPalette::Green, true),
+                Color32::from_rgb(155, 233, 168)
+            );
+            assert_eq!(
+                activity_color(1., ActivityPalette::Green, true),
+                Color32::from_rgb(33, 110, 57)
+            );
+            assert_eq!(
+                activity_color(0., ActivityPalette::Viridis, false),
+                Color32::from_rgb(68, 1, 84)
+            );
+            assert_eq!(
+                activity_color(1., ActivityPalette::Viridis, false),
+                Color32::from_rgb(253, 231, 37)
+            );
+        }
+
+        #[test]
+        fn activity_color_is_clamped() {
+            for palette in [
+                ActivityPalette::Green,
+                ActivityPalette::Blue,
+                ActivityPalette::Viridis,
+            ] {
+                for white_theme in [true, false] {
+                    assert_eq!(
+                        activity_color(-1., palette, white_theme),
+                        activity_color(0., palette, white_theme)
+                    );
+                    assert_eq!(
+                        activity_color(2., palette, white_theme),
+                        activity_color(1., palette, white_theme)
+                    );
+                }
+            }
+        }
+
+        #[test]
+        fn viridis_middle_is_middle_stop() {
+            assert_eq!(
+                activity_color(0.5, ActivityPalette::Viridis, true),
+                Color32::from_rgb(33, 145, 140)
+            );
+        }
+
+        #[test]
+        fn palettes_differ() {
+            let green = activity_color(1., ActivityPalette::Green, false);
+            let blue = activity_color(1., ActivityPalette::Blue, false);
+            let viridis = activity_color(1., ActivityPalette::Viridis, false);
+            assert_ne!(green, blue);
+            assert_ne!(green, viridis);
+            assert_ne!(blue, viridis);
+        }
+    }
+}
+
+/// Добавляет время с прошлого кадра к рабочему времени. Кадры рисуются только при вводе, поэтому промежуток может длиться несколько секунд, пока пользователь думает. Отрицательный промежуток после перевода часов не учитывается, а промежуток длиннее паузы `threshold` обрезается до неё.
+fn accumulate(prev: f64, now: f64, last: f64, paused: bool, threshold: f64) -> f64 {
+    if paused {
+        prev
+    } else {
+        prev + (now - last).clamp(0., threshold)
+    }
 }
 
 struct PauseDetector {
     last_mouse_position: (f32, f32),
-    pausing: bool,
-    time: f64,
+    /// Время последнего действия пользователя
+    last_activity: f64,
 
     last_time: f64,
     time_without_pauses: f64,
@@ -4079,8 +10122,7 @@ impl PauseDetector {
     fn new(time_today: f64) -> Self {
         Self {
             last_mouse_position: (0., 0.),
-            pausing: false,
-            time: now(),
+            last_activity: now(),
             last_time: now(),
             time_without_pauses: time_today,
         }
@@ -4093,29 +10135,34 @@ impl PauseDetector {
         };
         let mouse_offset = (self.last_mouse_position.0 - current_mouse_position.0).abs()
             + (self.last_mouse_position.1 - current_mouse_position.1).abs();
-        let mouse_not_moving = mouse_offset < 0.01;
-        let mouse_not_clicking = !input.pointer.any_down();
-        let keyboard_not_typing = input.keys_down.is_empty();
+        let mouse_moving = mouse_offset >= settings.pause_mouse_threshold;
+        let mouse_clicking = input.pointer.any_down();
+        let scrolling = input.scroll_delta != egui::Vec2::ZERO;
+        // Автоповтор удерживаемой клавиши не всегда виден в keys_down, но каждый введённый символ приходит событием
+        let keyboard_typing = !input.keys_down.is_empty()
+            || input
+                .events
+                .iter()
+                .any(|x| matches!(x, egui::Event::Text(_) | egui::Event::Key { .. }));
 
         self.last_mouse_position = current_mouse_position;
         let now = now();
-        if !(self.pausing && now - self.time > settings.time_to_pause) {
-            self.time_without_pauses += now - self.last_time;
-        }
+        let active = mouse_moving || mouse_clicking || scrolling || keyboard_typing;
+
+        // Пауза наступает только после `time_to_pause` секунд без каких-либо действий. Она считается по прошлому действию, иначе первое действие после долгого простоя засчитало бы весь простой в рабочее время.
+        let paused = now - self.last_activity > settings.time_to_pause;
+        self.time_without_pauses = accumulate(
+            self.time_without_pauses,
+            now,
+            self.last_time,
+            paused,
+            settings.time_to_pause,
+        );
         self.last_time = now;
-
-        if mouse_not_moving && keyboard_not_typing && mouse_not_clicking {
-            if self.pausing {
-                now - self.time > settings.time_to_pause
-            } else {
-                self.pausing = true;
-                self.time = now;
-                false
-            }
-        } else {
-            self.pausing = false;
-            false
+        if active {
+            self.last_activity = now;
         }
+        paused && !active
     }
 
     fn get_working_time(&mut self) -> &mut f64 {
@@ -4126,7 +10173,6 @@ impl PauseDetector {
 use eframe::{egui, epi};
 
 pub struct TemplateApp {
-    rng: Rand,
     today: Day,
     pause_detector: PauseDetector,
     program: gui::Program,
@@ -4141,14 +10187,16 @@ impl Default for TemplateApp {
         #[cfg(target_arch = "wasm32")]
         std::panic::set_hook(Box::new(console_error_panic_hook::hook));
 
-        fn current_day(hour_offset: f64) -> Day {
-            Day(((now() / 60. / 60. + hour_offset) / 24.) as _)
-        }
-
-        let mut rng = Rand::seed_from_u64(now() as u64);
-
-        let (words, settings, stats) = gui::Program::load();
-        let today = current_day(timezone_offset_hours());
+        let (words, settings, stats, load_error) = match gui::Program::load() {
+            Ok((words, settings, stats)) => (words, settings, stats, None),
+            Err(error) => (
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                Some(format!("{:#?}", error)),
+            ),
+        };
+        let today = current_day(settings.day_hour_offset());
 
         let mut pause_detector = PauseDetector::new(
             stats
@@ -4162,13 +10210,12 @@ impl Default for TemplateApp {
             words,
             settings,
             stats,
+            load_error,
             today,
             *pause_detector.get_working_time(),
-            &mut rng,
         );
 
         Self {
-            rng,
             today,
             pause_detector,
             program,
@@ -4182,17 +10229,9 @@ impl epi::App for TemplateApp {
         "Learn Words"
     }
 
-    fn update(&mut self, ctx: &egui::CtxRef, _: &mut epi::Frame<'_>) {
+    fn update(&mut self, ctx: &egui::CtxRef, frame: &mut epi::Frame<'_>) {
         if !self.init {
             self.init = true;
-
-            if self.program.get_settings().white_theme {
-                ctx.set_visuals(egui::Visuals::light());
-            } else {
-                ctx.set_visuals(egui::Visuals::dark());
-            }
-
-            ctx.set_pixels_per_point(self.program.get_settings().dpi);
         }
 
         let mut fill = ctx.style().visuals.extreme_bg_color;
@@ -4202,8 +10241,18 @@ impl epi::App for TemplateApp {
             let [r, g, b, _] = fill.to_array();
             fill = egui::Color32::from_rgba_premultiplied(r, g, b, 180);
         }
-        let frame = egui::Frame::none().fill(fill);
-        egui::CentralPanel::default().frame(frame).show(ctx, |_| {});
+        let panel_frame = egui::Frame::none().fill(fill);
+        egui::CentralPanel::default()
+            .frame(panel_frame)
+            .show(ctx, |_| {});
+
+        // Приложение могло остаться открытым после начала нового дня
+        let today = current_day(self.program.get_settings().day_hour_offset());
+        if today != self.today {
+            self.program
+                .change_day(self.today, today, self.pause_detector.get_working_time());
+            self.today = today;
+        }
 
         let paused = self
             .pause_detector
@@ -4212,13 +10261,31 @@ impl epi::App for TemplateApp {
             ctx,
             self.today,
             self.pause_detector.get_working_time(),
-            &mut self.rng,
             paused,
+            frame.tex_allocator(),
         );
+
+        // Запоминаем размер окна, чтобы в следующий раз открыть его таким же
+        if !cfg!(target_arch = "wasm32") {
+            if let Some(native_pixels_per_point) = frame.info().native_pixels_per_point {
+                let size = ctx.input().screen_rect.size() * ctx.pixels_per_point()
+                    / native_pixels_per_point;
+                self.program.set_window_size(size);
+            }
+        }
     }
 }
 
-// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+fn current_day(hour_offset: f64) -> Day {
+    day_at(now(), hour_offset)
+}
+
+/// Номер дня для времени в секундах от 1970-01-01 по UTC, сдвинутого на `hour_offset` часов
+fn day_at(time: f64, hour_offset: f64) -> Day {
+    Day(((time / 60. / 60. + hour_offset) / 24.) as _)
+}
 
 fn timezone_offset_hours() -> f64 {
     #[cfg(not(target_arch = "wasm32"))]
@@ -4235,6 +10302,19 @@ fn timezone_offset_hours() -> f64 {
     }
 }
 
+/// Зерно генератора случайных чисел. В нативной версии его можно задать через переменную окружения `LEARN_WORDS_SEED`, чтобы сессию можно было воспроизвести
+fn rng_seed() -> u64 {
+    #[cfg(not(target_arch = "wasm32"))]
+    if let Some(seed) = std::env::var("LEARN_WORDS_SEED")
+        .ok()
+        .and_then(|x| x.parse().ok())
+    {
+        return seed;
+    }
+
+    now() as u64
+}
+
 pub fn now() -> f64 {
     #[cfg(not(target_arch = "wasm32"))]
     {
@@ -4300,6 +10380,1070 @@ fn main() {}
 #[cfg(not(target_arch = "wasm32"))]
 fn main() {
     let app = TemplateApp::default();
-    let native_options = eframe::NativeOptions::default();
+    let native_options = eframe::NativeOptions {
+        initial_window_size: Some(app.program.window_size()),
+        ..Default::default()
+    };
     eframe::run_native(Box::new(app), native_options);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    pub(super) fn to_learn(translation: &str, last_learn: Day, current_level: u8) -> WordStatus {
+        let mut status = WordStatus::new_to_learn(
+            translation.to_string(),
+            last_learn,
+            Default::default(),
+            Default::default(),
+        );
+        if let WordStatus::ToLearn {
+            current_level: level,
+            ..
+        } = &mut status
+        {
+            *level = current_level;
+        }
+        status
+    }
+
+    pub(super) fn learned(translation: &str) -> WordStatus {
+        WordStatus::new_learned(
+            translation.to_string(),
+            Default::default(),
+            Default::default(),
+        )
+    }
+
+    pub(super) fn words(list: Vec<(&str, WordStatus)>) -> Words {
+        let mut result = Words::default();
+        for (word, status) in list {
+            result.0.entry(word.to_string()).or_default().push(status);
+        }
+        result
+    }
+
+    fn extracted_words(text: &str, case_sensitive: bool, options: TokenizeOptions) -> Vec<String> {
+        get_words(text, case_sensitive, options)
+            .words_with_context
+            .0
+            .into_iter()
+            .map(|(word, _)| word)
+            .collect()
+    }
+
+    fn add_to_learn(
+        words: &mut Words,
+        word: &str,
+        translation: &str,
+        today: Day,
+        day_stats: &mut DayStatistics,
+        settings: &Settings,
+    ) -> usize {
+        words.add_word(
+            word.to_string(),
+            WordsToAdd::ToLearn {
+                learned: Vec::new(),
+                translations: vec![translation.to_string()],
+                direction: LearnDirection::Forward,
+                tags: BTreeSet::new(),
+            },
+            today,
+            day_stats,
+            settings,
+        )
+    }
+
+    fn last_learn(words: &Words, word: &str) -> Day {
+        match &words.get(word).unwrap()[0] {
+            WordStatus::ToLearn { last_learn, .. } => *last_learn,
+            status => panic!("not a word to learn: {:?}", status),
+        }
+    }
+
+    fn ease(status: &WordStatus) -> f32 {
+        match status {
+            WordStatus::ToLearn { ease, .. } => *ease,
+            _ => panic!("not a word to learn: {:?}", status),
+        }
+    }
+
+    #[test]
+    fn reset_word_is_due_today_at_level_zero() {
+        let settings = Settings::default();
+        let mut suspended = to_learn("мышь", Day(100), 2);
+        suspended.suspend();
+        let mut statuses = vec![to_learn("кот", Day(100), 3), learned("собака"), suspended];
+        for status in &mut statuses {
+            status.reset_progress(Day(500));
+            assert_eq!(level_and_count(status), (0, 0));
+            assert!((ease(status) - DEFAULT_EASE).abs() < 1e-6);
+            assert_eq!(status.next_due_date(&settings.type_count), Some(Day(500)));
+            assert!(status.can_learn_today(Day(500), &settings.type_count));
+        }
+        assert_eq!(statuses[1].translation(), Some("собака"));
+    }
+
+    #[test]
+    fn next_due_date_by_level() {
+        let settings = Settings::default();
+        let words = words(vec![
+            ("new", to_learn("новое", Day(100), 0)),
+            ("middle", to_learn("среднее", Day(100), 3)),
+            ("known", learned("известное")),
+        ]);
+        assert_eq!(words.next_due_date("new", &settings), Some(Day(100)));
+        assert_eq!(words.next_due_date("middle", &settings), Some(Day(107)));
+        assert_eq!(words.next_due_date("known", &settings), None);
+        assert_eq!(words.next_due_date("missing", &settings), None);
+    }
+
+    #[test]
+    fn next_due_date_is_earliest_translation() {
+        let settings = Settings::default();
+        let words = words(vec![
+            ("word", to_learn("первый", Day(100), 3)),
+            ("word", to_learn("второй", Day(100), 2)),
+            ("word", learned("третий")),
+        ]);
+        assert_eq!(words.next_due_date("word", &settings), Some(Day(102)));
+    }
+
+    #[test]
+    fn case_sensitive_answers() {
+        let mut settings = Settings::default();
+        assert_eq!(normalize_answer("Straße", &settings), "straße");
+        assert_ne!(
+            normalize_answer("Straße", &settings),
+            normalize_answer("strasse", &settings)
+        );
+
+        settings.case_sensitive = true;
+        assert_ne!(
+            normalize_answer("Straße", &settings),
+            normalize_answer("straße", &settings)
+        );
+        assert_eq!(normalize_answer("Straße", &settings), "Straße");
+    }
+
+    #[test]
+    fn accent_sensitive_answers() {
+        let mut settings = Settings::default();
+        assert_ne!(
+            normalize_answer("café", &settings),
+            normalize_answer("cafe", &settings)
+        );
+
+        settings.accent_sensitive = false;
+        assert_eq!(
+            normalize_answer("café", &settings),
+            normalize_answer("cafe", &settings)
+        );
+        assert_eq!(
+            normalize_answer("Straße", &settings),
+            normalize_answer("strasse", &settings)
+        );
+    }
+
+    #[test]
+    fn case_sensitive_words_keep_casing() {
+        let options = TokenizeOptions::default();
+        assert_eq!(
+            extracted_words("Die Straße", false, options),
+            vec!["die", "straße"]
+        );
+        assert_eq!(
+            extracted_words("Die Straße", true, options),
+            vec!["Die", "Straße"]
+        );
+    }
+
+    const TOKENIZE_TEXT: &str = "don't —dash— 'quoted' mother-in-law";
+
+    #[test]
+    fn hyphens_and_apostrophes_are_kept_by_default() {
+        assert_eq!(
+            extracted_words(TOKENIZE_TEXT, false, TokenizeOptions::default()),
+            vec!["'quoted'", "dash", "don't", "mother-in-law"]
+        );
+    }
+
+    #[test]
+    fn hyphens_separate_and_apostrophes_are_stripped() {
+        let options = TokenizeOptions {
+            hyphen_separates: true,
+            strip_apostrophes: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            extracted_words(TOKENIZE_TEXT, false, options),
+            vec!["dash", "don't", "in", "law", "mother", "quoted"]
+        );
+    }
+
+    #[test]
+    fn stripped_word_range_points_to_word() {
+        let options = TokenizeOptions {
+            strip_apostrophes: true,
+            ..Default::default()
+        };
+        let result = get_words(TOKENIZE_TEXT, false, options);
+        let (_, ranges) = result
+            .words_with_context
+            .0
+            .iter()
+            .find(|(word, _)| word == "quoted")
+            .unwrap();
+        assert_eq!(&TOKENIZE_TEXT[ranges[0].clone()], "quoted");
+        assert!(extracted_words("'' ''", false, options).is_empty());
+    }
+
+    #[test]
+    fn digits_separate_words_by_default() {
+        assert_eq!(
+            extracted_words("covid19 2nd 100", false, TokenizeOptions::default()),
+            vec!["covid", "nd"]
+        );
+    }
+
+    #[test]
+    fn digits_are_kept_in_words() {
+        let options = TokenizeOptions {
+            alphanumeric: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            extracted_words("covid19 2nd 100", false, options),
+            vec!["100", "2nd", "covid19"]
+        );
+        let options = TokenizeOptions {
+            skip_numbers: true,
+            ..options
+        };
+        assert_eq!(
+            extracted_words("covid19 2nd 100", false, options),
+            vec!["2nd", "covid19"]
+        );
+    }
+
+    #[test]
+    fn chunked_extraction_matches_whole_text() {
+        let text = "Кот и cat. Ёжик-hedgehog don't спит; КОТ ест, Cat sleeps. Мышь mouse\nкот";
+        let expected = get_words(text, false, TokenizeOptions::default());
+        // По одному и по три байта слова и двухбайтовые буквы разрезаются границами кусков
+        for max_bytes in [1, 3] {
+            let mut extractor =
+                WordsExtractor::new(text.to_owned(), false, TokenizeOptions::default());
+            while !extractor.step(max_bytes) {}
+            let result = extractor.finish();
+            assert_eq!(result.words_with_context.0, expected.words_with_context.0);
+            assert_eq!(result.words_count, expected.words_count);
+        }
+        assert_eq!(expected.words_count, 13);
+    }
+
+    #[test]
+    fn english_stems_match_porter() {
+        for (word, expected) in [
+            ("caresses", "caress"),
+            ("ponies", "poni"),
+            ("hopping", "hop"),
+            ("relational", "relat"),
+            ("generalizations", "gener"),
+        ] {
+            assert_eq!(stem_english(word), expected, "{}", word);
+        }
+        assert_eq!(stem_english("Cats"), "Cats");
+    }
+
+    #[test]
+    fn russian_stems_drop_longest_ending() {
+        assert_eq!(stem_russian("книгами"), "книг");
+        assert_eq!(stem_russian("книга"), "книг");
+        assert_eq!(stem_russian("ёлками"), "елк");
+        // Основа короче трёх букв не остаётся
+        assert_eq!(stem_russian("дома"), "дом");
+        assert_eq!(stem_russian("дом"), "дом");
+    }
+
+    #[test]
+    fn merged_forms_keep_most_frequent_word() {
+        let mut words = WordsWithContext(vec![
+            ("cats".to_string(), vec![10..14]),
+            ("dog".to_string(), vec![5..8]),
+            ("cat".to_string(), vec![20..23, 0..3]),
+        ]);
+        assert_eq!(words.merge_forms(Lang::English), 1);
+        assert_eq!(
+            words.0,
+            vec![
+                ("cat".to_string(), vec![0..3, 10..14, 20..23]),
+                ("dog".to_string(), vec![5..8]),
+            ]
+        );
+    }
+
+    const RUSSIAN_SUBTITLES: &str = "1
+00:00:01,000 --> 00:00:03,000
+Привет, как дела? Всё хорошо.
+
+2
+00:00:04,000 --> 00:00:06,000
+Ёжик в тумане — это мультфильм.
+";
+
+    #[test]
+    fn context_of_russian_subtitles() {
+        let text = subtitles_text(RUSSIAN_SUBTITLES).unwrap();
+        let result = get_words(&text, false, TokenizeOptions::default());
+        assert!(!result.words_with_context.0.is_empty());
+        for (word, ranges) in &result.words_with_context.0 {
+            for range in ranges {
+                assert_eq!(text[range.clone()].to_lowercase(), *word);
+                for max in 0..20 {
+                    let context = sentence_context(&text, range.clone(), max);
+                    assert!(text[context.clone()].contains(&text[range.clone()]));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn snap_range_inside_multibyte_char() {
+        let text = "Привет";
+        // Каждая буква занимает два байта, поэтому 1 и 3 попадают в середину буквы
+        assert_eq!(snap_to_char_boundaries(text, 1..3), 0..4);
+        assert_eq!(snap_to_char_boundaries(text, 3..100), 2..text.len());
+        assert_eq!(&text[sentence_context(text, 3..5, 1)], "Прив");
+    }
+
+    #[test]
+    fn subtitle_tags_are_removed() {
+        assert_eq!(
+            clean_subtitle_text(r"<i>Hello</i> {\an8}world"),
+            "Hello world"
+        );
+        assert_eq!(
+            clean_subtitle_text(r#"<font color="red">Bye</font>"#),
+            "Bye"
+        );
+
+        let subtitles = "1
+00:00:01,000 --> 00:00:03,000
+<i>Hello</i> {\\an8}world
+";
+        let text = subtitles_text(subtitles).unwrap();
+        assert_eq!(
+            extracted_words(&text, false, TokenizeOptions::default()),
+            vec!["hello", "world"]
+        );
+    }
+
+    #[test]
+    fn words_over_daily_limit_are_deferred() {
+        let settings = Settings {
+            daily_new_limit: 2,
+            ..Default::default()
+        };
+        let today = Day(100);
+        let mut day_stats = DayStatistics::default();
+        let mut words = Words::default();
+        for word in ["a", "b", "c", "d", "e"] {
+            add_to_learn(
+                &mut words,
+                word,
+                "перевод",
+                today,
+                &mut day_stats,
+                &settings,
+            );
+        }
+
+        assert_eq!(words.len(), 5);
+        assert_eq!(day_stats.new_unknown_words_count, 2);
+        assert_eq!(last_learn(&words, "a"), today);
+        assert_eq!(last_learn(&words, "b"), today);
+        assert_eq!(last_learn(&words, "c"), Day(101));
+        assert_eq!(last_learn(&words, "d"), Day(101));
+        assert_eq!(last_learn(&words, "e"), Day(102));
+
+        assert!(!words.can_learn_today("c", today, &settings));
+        assert!(words.can_learn_today("c", Day(101), &settings));
+    }
+
+    #[test]
+    fn deferred_words_count_towards_limit_of_their_day() {
+        let settings = Settings {
+            daily_new_limit: 2,
+            ..Default::default()
+        };
+        let mut words = Words::default();
+        let mut stats = Statistics::default();
+        let add_words = |words: &mut Words, stats: &mut Statistics, today, names: &[&str]| {
+            stats.start_day(today, words);
+            for word in names {
+                add_to_learn(
+                    words,
+                    word,
+                    "перевод",
+                    today,
+                    stats.by_day.entry(today).or_default(),
+                    &settings,
+                );
+            }
+        };
+        add_words(&mut words, &mut stats, Day(100), &["a", "b", "c", "d"]);
+        add_words(&mut words, &mut stats, Day(101), &["e", "f"]);
+        add_words(&mut words, &mut stats, Day(102), &["g"]);
+
+        assert_eq!(stats.by_day[&Day(101)].new_unknown_words_count, 2);
+        assert_eq!(last_learn(&words, "e"), Day(102));
+        assert_eq!(last_learn(&words, "f"), Day(102));
+        assert_eq!(last_learn(&words, "g"), Day(103));
+
+        let per_day = words.new_pairs_by_day(Day(100));
+        assert_eq!(per_day.values().max(), Some(&2));
+        assert_eq!(per_day.values().sum::<u64>(), 7);
+
+        // Повторное начало того же дня ничего не меняет
+        stats.start_day(Day(102), &words);
+        assert_eq!(stats.by_day[&Day(102)].new_unknown_words_count, 2);
+    }
+
+    #[test]
+    fn review_limit_keeps_most_overdue_words() {
+        let settings = Settings::default();
+        let words = words(vec![
+            ("new", to_learn("новое", Day(100), 0)),
+            ("a", to_learn("а", Day(90), 2)),
+            ("b", to_learn("б", Day(50), 2)),
+            ("c", to_learn("в", Day(80), 2)),
+            ("d", to_learn("г", Day(99), 2)),
+        ]);
+        let (repeat, new) =
+            words.get_words_to_learn_today(Day(100), &settings, 2, &BTreeSet::new(), None);
+        assert_eq!(repeat, vec!["b", "c"]);
+        assert_eq!(new, vec!["new"]);
+    }
+
+    #[test]
+    fn stats_csv_has_column_per_word_type() {
+        let mut stats = Statistics::default();
+        let day = stats.by_day.entry(Day(18628)).or_default();
+        day.attempts = TypingStats { right: 5, wrong: 2 };
+        day.new_unknown_words_count = 3;
+        day.working_time = 60.5;
+        day.word_count_by_level.insert(WordType::Level(0), 4);
+        day.word_count_by_level.insert(WordType::Learned, 1);
+        let day = stats.by_day.entry(Day(18629)).or_default();
+        day.word_count_by_level.insert(WordType::Known, 7);
+
+        assert_eq!(
+            stats_to_csv(&stats),
+            "day,date,right,wrong,new_words,working_time,known,level_0,learned
+18628,2021-01-01,5,2,3,60.5,0,4,1
+18629,2021-01-02,0,0,0,0,7,0,0
+"
+        );
+    }
+
+    #[test]
+    fn stats_csv_without_days() {
+        assert_eq!(
+            stats_to_csv(&Statistics::default()),
+            "day,date,right,wrong,new_words,working_time\n"
+        );
+    }
+
+    #[test]
+    fn merge_disjoint_words() {
+        let mut local = words(vec![("cat", to_learn("кот", Day(100), 1))]);
+        let other = words(vec![
+            ("dog", to_learn("собака", Day(100), 2)),
+            ("the", WordStatus::KnowPreviously),
+        ]);
+        local.merge(other);
+        assert_eq!(local.len(), 3);
+        assert_eq!(local.get("cat").unwrap()[0].level(), Some(1));
+        assert_eq!(local.get("dog").unwrap()[0].level(), Some(2));
+        assert!(matches!(
+            local.get("the").unwrap(),
+            [WordStatus::KnowPreviously]
+        ));
+    }
+
+    #[test]
+    fn merge_overlapping_words_keeps_more_advanced() {
+        let mut local = words(vec![
+            ("cat", to_learn("кот", Day(100), 1)),
+            ("cat", to_learn("кошка", Day(100), 3)),
+            ("the", WordStatus::KnowPreviously),
+        ]);
+        let other = words(vec![
+            ("cat", to_learn("кот", Day(100), 2)),
+            ("cat", to_learn("кошка", Day(100), 0)),
+            ("cat", learned("котик")),
+            ("the", WordStatus::KnowPreviously),
+        ]);
+        local.merge(other);
+
+        let cat = local.get("cat").unwrap();
+        assert_eq!(cat.len(), 3);
+        assert_eq!(cat[0].translation(), Some("кот"));
+        assert_eq!(cat[0].level(), Some(2));
+        assert_eq!(cat[1].translation(), Some("кошка"));
+        assert_eq!(cat[1].level(), Some(3));
+        assert!(matches!(cat[2], WordStatus::Learned { .. }));
+        assert_eq!(local.get("the").unwrap().len(), 1);
+    }
+
+    #[test]
+    fn merge_statistics_sums_attempts() {
+        let mut local = Statistics::default();
+        let day = local.by_day.entry(Day(100)).or_default();
+        day.attempts = TypingStats { right: 1, wrong: 2 };
+        day.working_time = 10.;
+        day.word_count_by_level.insert(WordType::Learned, 5);
+
+        let mut other = Statistics::default();
+        let day = other.by_day.entry(Day(100)).or_default();
+        day.attempts = TypingStats { right: 3, wrong: 4 };
+        day.working_time = 20.;
+        day.word_count_by_level.insert(WordType::Learned, 7);
+        other
+            .by_day
+            .entry(Day(101))
+            .or_default()
+            .new_unknown_words_count = 6;
+
+        local.merge(other);
+        let day = &local.by_day[&Day(100)];
+        assert_eq!((day.attempts.right, day.attempts.wrong), (4, 6));
+        assert_eq!(day.working_time, 30.);
+        assert_eq!(day.word_count_by_level[&WordType::Learned], 5);
+        assert_eq!(local.by_day[&Day(101)].new_unknown_words_count, 6);
+    }
+
+    /// Сохранение из версии без номера: кортеж из слов, настроек и статистики
+    const SAVE_V0: &str = r#"(
+        ({
+            "cat": [ToLearn(translation: "кот", last_learn: (100), current_level: 1, current_count: 0, stats: (right: 3, wrong: 1))],
+            "кот": [ToLearn(translation: "cat", last_learn: (100), current_level: 0, current_count: 1, stats: (right: 1, wrong: 0))],
+            "the": [KnowPreviously],
+            "asdf": [TrashWord],
+        }),
+        (
+            type_count: [(wait_days: 0, count: 2, show_word: true), (wait_days: 3, count: 0, show_word: false)],
+            time_to_pause: 15.0,
+            use_keyboard_layout: true,
+            keyboard_layout: (lang1: {'q': 'й', 'w': 'ц'}, lang2: {'й': 'q', 'ц': 'w'}),
+            dpi: 1.5,
+            white_theme: true,
+        ),
+        (
+            by_day: {
+                (100): (attempts: (right: 4, wrong: 1), new_unknown_words_count: 2, word_count_by_level: {Level(0): 1, Level(1): 1, Known: 1, Trash: 1}, working_time: 30.0),
+            },
+        ),
+    )"#;
+
+    #[test]
+    fn load_v0_save() {
+        let data = migrate(SaveData::from_str(SAVE_V0).unwrap());
+        assert_eq!(data.version, SAVE_VERSION);
+
+        assert_eq!(data.words.len(), 4);
+        let cat = &data.words.get("cat").unwrap()[0];
+        assert_eq!(cat.translation(), Some("кот"));
+        assert_eq!(cat.level(), Some(1));
+        assert_eq!(ease(cat), DEFAULT_EASE);
+        assert!(cat.tags().unwrap().is_empty());
+        assert!(cat.history().is_empty());
+        assert!(matches!(
+            data.words.get("asdf").unwrap(),
+            [WordStatus::TrashWord]
+        ));
+
+        let settings = &data.settings;
+        assert_eq!(settings.type_count.len(), 2);
+        assert_eq!(settings.type_count[1].count, 0);
+        assert_eq!(settings.ui_scale, 1.5);
+        assert!(matches!(settings.theme, Theme::Light));
+        assert_eq!(settings.keyboard_layout.profiles.len(), 2);
+        let mut text = "qw".to_string();
+        settings.keyboard_layout.change(0, 1, &mut text);
+        assert_eq!(text, "йц");
+        assert!(settings.accent_sensitive);
+        assert_eq!(settings.context_max_length, default_context_max_length());
+
+        let day = &data.stats.by_day[&Day(100)];
+        assert_eq!(day.attempts.right, 4);
+        assert_eq!(day.working_time, 30.);
+        assert_eq!(day.reviewed_words_count, 0);
+    }
+
+    #[test]
+    fn level_without_repeats_is_passed_by_one() {
+        let settings = Settings {
+            type_count: vec![LearnType::guess(0, 0), LearnType::guess(0, 1)],
+            ..Default::default()
+        };
+        let mut day_stats = DayStatistics::default();
+        let mut status = to_learn("кот", Day(100), 0);
+        assert_eq!(status.attempts_remains(Day(100), &settings.type_count), 1);
+        assert!(status.register_attempt(true, Day(100), &mut day_stats, &settings));
+        assert_eq!(status.level(), Some(1));
+    }
+
+    fn three_layouts() -> KeyboardLayout {
+        KeyboardLayout::new(&[
+            ("English".to_string(), "qwe".to_string()),
+            ("Russian".to_string(), "йцу".to_string()),
+            ("Greek".to_string(), ";ςε".to_string()),
+        ])
+        .unwrap()
+    }
+
+    #[test]
+    fn empty_answer_is_not_changed() {
+        let layout = three_layouts();
+        assert_eq!(layout.profile_of(""), None);
+        let mut text = String::new();
+        layout.change(0, 1, &mut text);
+        assert_eq!(text, "");
+    }
+
+    #[test]
+    fn answer_is_changed_to_other_layout() {
+        let layout = three_layouts();
+        let mut text = "qwe".to_string();
+        layout.change(0, 1, &mut text);
+        assert_eq!(text, "йцу");
+    }
+
+    fn layout(first: &str, second: &str) -> Result<KeyboardLayout, String> {
+        KeyboardLayout::new(&[
+            ("English".to_string(), first.to_string()),
+            ("Russian".to_string(), second.to_string()),
+        ])
+    }
+
+    #[test]
+    fn placeholders_are_not_mapped() {
+        let layout = layout("q e", "й у").unwrap();
+        assert!(layout
+            .profiles
+            .iter()
+            .all(|(_, map)| !map.contains_key(&' ') && !map.values().any(|x| *x == ' ')));
+        let mut text = "q e".to_string();
+        layout.change(0, 1, &mut text);
+        assert_eq!(text, "й у");
+    }
+
+    #[test]
+    fn symbol_at_first_layout_placeholder_is_kept() {
+        let layout = layout("q w", "йцу").unwrap();
+        assert_eq!(layout.profile_of("ц"), Some(1));
+
+        let mut text = "йцу".to_string();
+        layout.change(1, 0, &mut text);
+        assert_eq!(text, "qцw");
+
+        // Раскладка, сохранённая в текст, читается обратно такой же
+        let symbols: Vec<(String, String)> = (0..layout.profiles.len())
+            .map(|i| (layout.profiles[i].0.clone(), layout.profile_symbols(i)))
+            .collect();
+        let again = KeyboardLayout::new(&symbols).unwrap();
+        assert_eq!(again.profiles, layout.profiles);
+    }
+
+    #[test]
+    fn layout_without_symbols_is_error() {
+        assert!(layout("   ", "йцу").unwrap_err().contains("English"));
+        assert!(layout("", "").unwrap_err().contains("English"));
+        assert!(layout("qwe", "   ").unwrap_err().contains("Russian"));
+    }
+
+    #[test]
+    fn speech_lang_of_each_layout() {
+        let settings = Settings {
+            keyboard_layout: three_layouts(),
+            speech_langs: vec!["en".to_string(), "ru".to_string(), "el".to_string()],
+            ..Default::default()
+        };
+        assert_eq!(settings.speech_lang("we"), "en");
+        assert_eq!(settings.speech_lang("Уцй"), "ru");
+        assert_eq!(settings.speech_lang("ες"), "el");
+    }
+
+    #[test]
+    fn speech_lang_falls_back_to_script() {
+        let settings = Settings {
+            keyboard_layout: three_layouts(),
+            speech_langs: vec!["en-US".to_string()],
+            ..Default::default()
+        };
+        assert_eq!(settings.speech_lang("we"), "en-US");
+        assert_eq!(
+            settings.speech_lang("уц"),
+            Script::Cyrillic.default_speech_lang()
+        );
+    }
+
+    #[test]
+    fn migrate_two_speech_langs() {
+        let data = migrate(SaveData {
+            version: 3,
+            words: Words::default(),
+            settings: Settings {
+                speech_lang1: "en".to_string(),
+                speech_lang2: "ru".to_string(),
+                ..Default::default()
+            },
+            stats: Statistics::default(),
+        });
+        assert_eq!(data.settings.speech_langs, vec!["en", "ru"]);
+    }
+
+    fn add_both_ways(words: &mut Words, word: &str, translation: &str) {
+        words.add_word(
+            word.to_string(),
+            WordsToAdd::ToLearn {
+                learned: Vec::new(),
+                translations: vec![translation.to_string()],
+                direction: LearnDirection::Both,
+                tags: BTreeSet::new(),
+            },
+            Day(100),
+            &mut DayStatistics::default(),
+            &Settings::default(),
+        );
+    }
+
+    #[test]
+    fn purge_trash_removes_only_trash() {
+        let mut words = words(vec![
+            ("the", WordStatus::TrashWord),
+            ("a", WordStatus::TrashWord),
+            ("hello", WordStatus::KnowPreviously),
+            ("cat", to_learn("кот", Day(100), 0)),
+        ]);
+        assert_eq!(words.purge_trash(), 2);
+        assert!(!words.contains("the"));
+        assert!(!words.contains("a"));
+        assert!(words.contains("hello"));
+        assert!(words.contains("cat"));
+        assert_eq!(words.purge_trash(), 0);
+    }
+
+    #[test]
+    fn purge_trash_keeps_other_statuses_of_word() {
+        let mut words = words(vec![
+            ("cat", WordStatus::TrashWord),
+            ("cat", to_learn("кот", Day(100), 0)),
+        ]);
+        assert_eq!(words.purge_trash(), 1);
+        let statuses = words.get("cat").unwrap();
+        assert_eq!(statuses.len(), 1);
+        assert_eq!(statuses[0].translation(), Some("кот"));
+    }
+
+    #[test]
+    fn remove_word_removes_empty_reverse_entry() {
+        let mut words = Words::default();
+        add_both_ways(&mut words, "cat", "кот");
+        assert!(words.contains("кот"));
+
+        words.remove_word("cat");
+        assert!(!words.contains("cat"));
+        assert!(!words.contains("кот"));
+        assert!(words.is_empty());
+    }
+
+    #[test]
+    fn remove_word_keeps_other_reverse_links() {
+        let mut words = Words::default();
+        add_both_ways(&mut words, "cat", "кот");
+        add_both_ways(&mut words, "tomcat", "кот");
+
+        words.remove_word("cat");
+        let reverse = words.get("кот").unwrap();
+        assert_eq!(reverse.len(), 1);
+        assert_eq!(reverse[0].translation(), Some("tomcat"));
+    }
+
+    #[test]
+    fn rename_word_to_fresh_name() {
+        let mut words = Words::default();
+        add_both_ways(&mut words, "cat", "кот");
+
+        assert_eq!(words.rename_word("cat", "kitty"), Ok(()));
+        assert!(!words.contains("cat"));
+        assert_eq!(words.get("kitty").unwrap()[0].translation(), Some("кот"));
+        assert_eq!(words.get("кот").unwrap()[0].translation(), Some("kitty"));
+    }
+
+    #[test]
+    fn rename_word_onto_existing_word_is_rejected() {
+        let mut words = Words::default();
+        add_both_ways(&mut words, "cat", "кот");
+        add_both_ways(&mut words, "dog", "собака");
+
+        assert!(words.rename_word("cat", "dog").is_err());
+        assert_eq!(words.get("cat").unwrap()[0].translation(), Some("кот"));
+        assert_eq!(words.get("dog").unwrap()[0].translation(), Some("собака"));
+        assert_eq!(words.get("кот").unwrap()[0].translation(), Some("cat"));
+        assert_eq!(words.get("собака").unwrap()[0].translation(), Some("dog"));
+    }
+
+    #[test]
+    fn rename_missing_word_does_nothing() {
+        let mut words = Words::default();
+        add_both_ways(&mut words, "cat", "кот");
+        assert_eq!(words.rename_word("dog", "puppy"), Ok(()));
+        assert!(!words.contains("puppy"));
+        assert_eq!(words.len(), 2);
+    }
+
+    #[test]
+    fn attempt_on_learned_word_is_ignored() {
+        let settings = Settings::default();
+        let mut day_stats = DayStatistics::default();
+        let mut words = words(vec![("cat", to_learn("кот", Day(100), 0))]);
+        words.0.get_mut("cat").unwrap()[0].mark_learned();
+
+        assert!(!words.register_attempt("cat", "кот", true, Day(100), &mut day_stats, &settings));
+        assert!(!words.register_attempt("cat", "кот", false, Day(100), &mut day_stats, &settings));
+        assert!(matches!(
+            words.get("cat").unwrap(),
+            [WordStatus::Learned { .. }]
+        ));
+        assert_eq!(day_stats.attempts.right + day_stats.attempts.wrong, 0);
+    }
+
+    #[test]
+    fn attempt_on_known_or_missing_word_is_ignored() {
+        let settings = Settings::default();
+        let mut day_stats = DayStatistics::default();
+        let mut words = words(vec![("the", WordStatus::KnowPreviously)]);
+        assert!(!words.register_attempt("the", "", true, Day(100), &mut day_stats, &settings));
+        assert!(!words.register_attempt("missing", "x", true, Day(100), &mut day_stats, &settings));
+        assert_eq!(day_stats.attempts.right, 0);
+    }
+
+    /// Статистика, где в каждый из `active` дней была попытка, а в каждый из `idle` дней — нет
+    pub(super) fn stats_with_days(active: &[u64], idle: &[u64]) -> Statistics {
+        let mut stats = Statistics::default();
+        for day in active {
+            stats.by_day.entry(Day(*day)).or_default().attempts.right = 1;
+        }
+        for day in idle {
+            stats.by_day.entry(Day(*day)).or_default();
+        }
+        stats
+    }
+
+    #[test]
+    fn streaks_of_day_sequences() {
+        assert_eq!(compute_streaks(&Statistics::default()), (0, 0));
+        assert_eq!(compute_streaks(&stats_with_days(&[100], &[])), (1, 1));
+        assert_eq!(
+            compute_streaks(&stats_with_days(&[100, 101, 102], &[])),
+            (3, 3)
+        );
+        // Один пропущенный день прерывает серию
+        assert_eq!(
+            compute_streaks(&stats_with_days(&[100, 101, 102, 104, 105], &[])),
+            (2, 3)
+        );
+        // День без попыток тоже прерывает серию
+        assert_eq!(
+            compute_streaks(&stats_with_days(&[100, 101, 103], &[102])),
+            (1, 2)
+        );
+    }
+
+    #[test]
+    fn streak_continues_while_today_has_no_attempts() {
+        assert_eq!(
+            compute_streaks(&stats_with_days(&[100, 101], &[102])),
+            (2, 2)
+        );
+        assert_eq!(
+            compute_streaks(&stats_with_days(&[100, 101], &[103])),
+            (0, 2)
+        );
+    }
+
+    #[test]
+    fn accumulate_adds_frame_time() {
+        assert_eq!(accumulate(10., 5.5, 5., false, 15.), 10.5);
+    }
+
+    #[test]
+    fn accumulate_counts_thinking_time_in_full() {
+        // Между кадрами без ввода может пройти несколько секунд
+        assert_eq!(accumulate(10., 10., 5., false, 15.), 15.);
+    }
+
+    #[test]
+    fn accumulate_ignores_negative_delta() {
+        assert_eq!(accumulate(10., 4., 5., false, 15.), 10.);
+    }
+
+    #[test]
+    fn accumulate_caps_delta_at_pause() {
+        assert_eq!(accumulate(10., 3600., 5., false, 15.), 25.);
+    }
+
+    #[test]
+    fn accumulate_does_nothing_when_paused() {
+        assert_eq!(accumulate(10., 5.5, 5., true, 15.), 10.);
+    }
+
+    #[test]
+    fn day_starts_at_configured_hour() {
+        // 2021-01-01 02:00 UTC
+        let time = (18628 * 24 + 2) as f64 * 60. * 60.;
+        let settings = |day_start_hour, timezone_offset| Settings {
+            day_start_hour,
+            timezone_offset: Some(timezone_offset),
+            ..Default::default()
+        };
+        assert_eq!(day_at(time, settings(0, 0.).day_hour_offset()), Day(18628));
+        assert_eq!(day_at(time, settings(2, 0.).day_hour_offset()), Day(18628));
+        assert_eq!(day_at(time, settings(3, 0.).day_hour_offset()), Day(18627));
+        assert_eq!(day_at(time, settings(0, -3.).day_hour_offset()), Day(18627));
+        assert_eq!(day_at(time, settings(6, 3.).day_hour_offset()), Day(18627));
+        assert_eq!(day_at(time, settings(0, 22.).day_hour_offset()), Day(18629));
+    }
+
+    fn level_and_count(status: &WordStatus) -> (u8, u8) {
+        match status {
+            WordStatus::ToLearn {
+                current_level,
+                current_count,
+                ..
+            } => (*current_level, *current_count),
+            _ => panic!("word is not learned"),
+        }
+    }
+
+    #[test]
+    fn repeats_are_done_on_same_day() {
+        let settings = Settings {
+            type_count: vec![LearnType::guess(0, 3), LearnType::guess(5, 1)],
+            ..Default::default()
+        };
+        let mut day_stats = DayStatistics::default();
+        let mut status = to_learn("слово", Day(100), 0);
+
+        assert!(!status.register_attempt(true, Day(100), &mut day_stats, &settings));
+        assert!(!status.register_attempt(true, Day(100), &mut day_stats, &settings));
+        assert_eq!(level_and_count(&status), (0, 2));
+        assert!(status.register_attempt(true, Day(100), &mut day_stats, &settings));
+        assert_eq!(level_and_count(&status), (1, 0));
+    }
+
+    #[test]
+    fn per_day_repeats_wait_for_next_day() {
+        let settings = Settings {
+            type_count: vec![
+                LearnType {
+                    per_day: true,
+                    ..LearnType::guess(0, 3)
+                },
+                LearnType::guess(5, 1),
+            ],
+            ..Default::default()
+        };
+        let mut day_stats = DayStatistics::default();
+        let mut status = to_learn("слово", Day(100), 0);
+
+        assert!(!status.register_attempt(true, Day(100), &mut day_stats, &settings));
+        assert_eq!(level_and_count(&status), (0, 1));
+        assert!(!status.can_learn_today(Day(100), &settings.type_count));
+
+        // Повторная попытка в тот же день не засчитывается
+        assert!(!status.register_attempt(true, Day(100), &mut day_stats, &settings));
+        assert_eq!(level_and_count(&status), (0, 1));
+
+        assert!(status.can_learn_today(Day(101), &settings.type_count));
+        assert!(!status.register_attempt(true, Day(101), &mut day_stats, &settings));
+        assert_eq!(level_and_count(&status), (0, 2));
+        assert!(status.register_attempt(true, Day(102), &mut day_stats, &settings));
+        assert_eq!(level_and_count(&status), (1, 0));
+    }
+
+    #[test]
+    fn word_past_short_tag_schedule_is_learned() {
+        let mut settings = Settings::default();
+        settings.tag_schedules.insert(
+            "short".to_string(),
+            vec![LearnType::guess(0, 1), LearnType::guess(2, 1)],
+        );
+        let mut status = to_learn("слово", Day(100), 3);
+        if let WordStatus::ToLearn { tags, .. } = &mut status {
+            tags.insert("short".to_string());
+        }
+        let schedule = settings.schedule_for(&status);
+        assert_eq!(schedule.len(), 2);
+
+        // Слово повторяется по последнему уровню расписания темы
+        assert_eq!(status.next_due_date(schedule), Some(Day(102)));
+        assert!(!status.can_learn_today(Day(101), schedule));
+        assert!(status.can_learn_today(Day(102), schedule));
+
+        let words = words(vec![("word", status.clone())]);
+        assert_eq!(
+            words.projected_reviews(Day(100), 5, &settings)[&Day(102)],
+            1
+        );
+
+        let mut day_stats = DayStatistics::default();
+        assert!(status.register_attempt(true, Day(102), &mut day_stats, &settings));
+        assert!(matches!(status, WordStatus::Learned { .. }));
+        assert_eq!(day_stats.words_learned_today, 1);
+    }
+
+    #[test]
+    fn ease_grows_when_level_is_passed() {
+        let settings = Settings {
+            type_count: vec![LearnType::guess(0, 1), LearnType::guess(20, 1)],
+            ..Default::default()
+        };
+        let mut day_stats = DayStatistics::default();
+        let mut status = to_learn("слово", Day(100), 0);
+
+        assert!(status.register_attempt(true, Day(100), &mut day_stats, &settings));
+        assert!((ease(&status) - (DEFAULT_EASE + EASE_BONUS)).abs() < 1e-6);
+
+        // Следующий уровень ждёт дольше, чем 20 дней из расписания
+        assert!(!status.can_learn_today(Day(120), &settings.type_count));
+        assert!(status.can_learn_today(Day(121), &settings.type_count));
+    }
+
+    #[test]
+    fn ease_drops_on_wrong_answer_down_to_minimum() {
+        let settings = Settings::default();
+        let mut day_stats = DayStatistics::default();
+        let mut status = to_learn("слово", Day(100), 0);
+
+        status.register_attempt(false, Day(100), &mut day_stats, &settings);
+        assert!((ease(&status) - (DEFAULT_EASE - EASE_PENALTY)).abs() < 1e-6);
+
+        for _ in 0..20 {
+            status.register_attempt(false, Day(100), &mut day_stats, &settings);
+        }
+        assert_eq!(ease(&status), MIN_EASE);
+    }
+
+    #[test]
+    fn lower_ease_shortens_wait() {
+        let learn = LearnType::guess(10, 1);
+        assert_eq!(learn.scaled_wait_days(DEFAULT_EASE), 10);
+        assert_eq!(learn.scaled_wait_days(MIN_EASE), 5);
+        assert_eq!(learn.scaled_wait_days(DEFAULT_EASE + 5. * EASE_BONUS), 12);
+    }
+}
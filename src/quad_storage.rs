@@ -1,5 +1,5 @@
 use lazy_static::lazy_static;
-use std::sync::Mutex;
+use std::sync::{Mutex, MutexGuard};
 
 #[cfg(not(target_arch = "wasm32"))]
 use nanoserde::{DeJson, SerJson};
@@ -17,6 +17,52 @@ pub struct LocalStorage {
 #[cfg(not(target_arch = "wasm32"))]
 const LOCAL_FILE: &str = "local.data";
 
+#[cfg(not(target_arch = "wasm32"))]
+const TEMP_FILE: &str = "local.data.tmp";
+
+/// How many backups of the local file are kept.
+#[cfg(not(target_arch = "wasm32"))]
+pub const BACKUPS_COUNT: usize = 5;
+
+/// Minimal time between two backups, otherwise saving after every typed word would quickly replace all backups.
+#[cfg(not(target_arch = "wasm32"))]
+const BACKUP_INTERVAL: std::time::Duration = std::time::Duration::from_secs(60 * 60);
+
+#[cfg(not(target_arch = "wasm32"))]
+fn backup_file(n: usize) -> String {
+    format!("{}.bak.{}", LOCAL_FILE, n)
+}
+
+/// Is there a backup with this number. 1 is the newest one.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn backup_exists(n: usize) -> bool {
+    std::path::Path::new(&backup_file(n)).exists()
+}
+
+/// Shifts backups by one and copies the current file to the first backup, if the first backup is old enough.
+#[cfg(not(target_arch = "wasm32"))]
+fn rotate_backups() -> std::io::Result<()> {
+    if !std::path::Path::new(LOCAL_FILE).exists() {
+        return Ok(());
+    }
+    let last_backup_is_fresh = std::fs::metadata(backup_file(1))
+        .and_then(|x| x.modified())
+        .ok()
+        .and_then(|x| x.elapsed().ok())
+        .map(|x| x < BACKUP_INTERVAL)
+        .unwrap_or(false);
+    if last_backup_is_fresh {
+        return Ok(());
+    }
+    for n in (1..BACKUPS_COUNT).rev() {
+        if backup_exists(n) {
+            std::fs::rename(backup_file(n), backup_file(n + 1))?;
+        }
+    }
+    std::fs::copy(LOCAL_FILE, backup_file(1))?;
+    Ok(())
+}
+
 impl Default for LocalStorage {
     fn default() -> Self {
         #[cfg(target_arch = "wasm32")]
@@ -116,12 +162,35 @@ impl LocalStorage {
         }
     }
 
+    /// Replaces all data by the backup with number `n`. The current data goes to backups as usual.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn restore_backup(&mut self, n: usize) -> Result<(), String> {
+        let file = std::fs::read_to_string(backup_file(n)).map_err(|e| e.to_string())?;
+        *self = LocalStorage::deserialize_json(&file).map_err(|e| format!("{:?}", e))?;
+        self.save();
+        Ok(())
+    }
+
+    /// Writes to a temporary file and then renames it, so the data file is never left half-written.
     #[cfg(not(target_arch = "wasm32"))]
     fn save(&self) {
-        std::fs::write(LOCAL_FILE, self.serialize_json()).unwrap();
+        if let Err(error) = rotate_backups() {
+            eprintln!("Failed to backup {}: {}", LOCAL_FILE, error);
+        }
+        std::fs::write(TEMP_FILE, self.serialize_json()).unwrap();
+        std::fs::rename(TEMP_FILE, LOCAL_FILE).unwrap();
     }
 }
 
 lazy_static! {
     pub static ref STORAGE: Mutex<LocalStorage> = Mutex::new(Default::default());
 }
+
+/// Locks the storage. If another thread panicked while holding the lock, the storage itself is
+/// still intact, so the poisoned lock is recovered instead of failing every later save.
+pub fn storage() -> MutexGuard<'static, LocalStorage> {
+    STORAGE.lock().unwrap_or_else(|error| {
+        eprintln!("Storage lock was poisoned, recovering it");
+        error.into_inner()
+    })
+}